@@ -0,0 +1,44 @@
+//! Exposes the version of the linked `cargo-miden` crate as `CARGO_MIDEN_VERSION`
+//!
+//! `cargo-miden` is a git dependency, so its version is only known from the workspace lock file.
+//! The lock file isn't committed, so if it can't be read the version is reported as "unknown".
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+/// Version reported when it can't be read from the lock file
+const UNKNOWN_VERSION: &str = "unknown";
+
+fn main() {
+    let lock_path = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join("../Cargo.lock");
+    println!("cargo:rerun-if-changed={}", lock_path.display());
+
+    let version = match locked_cargo_miden_version(&lock_path) {
+        Ok(version) => version,
+        Err(reason) => {
+            println!("cargo:warning=cargo-miden version is unknown: {reason}");
+            UNKNOWN_VERSION.to_string()
+        }
+    };
+
+    println!("cargo:rustc-env=CARGO_MIDEN_VERSION={version}");
+}
+
+/// Reads the version of `cargo-miden` from the lock file at `lock_path`
+fn locked_cargo_miden_version(lock_path: &Path) -> Result<String, String> {
+    let lock: toml::Table = fs::read_to_string(lock_path)
+        .map_err(|err| format!("failed to read {}: {err}", lock_path.display()))?
+        .parse()
+        .map_err(|err| format!("failed to parse {}: {err}", lock_path.display()))?;
+
+    lock.get("package")
+        .and_then(toml::Value::as_array)
+        .into_iter()
+        .flatten()
+        .find(|package| package.get("name").and_then(toml::Value::as_str) == Some("cargo-miden"))
+        .and_then(|package| package.get("version").and_then(toml::Value::as_str))
+        .map(str::to_owned)
+        .ok_or_else(|| format!("cargo-miden isn't locked in {}", lock_path.display()))
+}
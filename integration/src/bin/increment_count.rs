@@ -1,14 +1,11 @@
 use integration::helpers::{
-    build_project_in_dir, create_account_from_package, create_basic_wallet_account,
-    create_note_from_package, setup_client, AccountCreationConfig, ClientSetup, NoteCreationConfig,
+    build_project_in_dir_async, create_basic_wallet_account, create_counter_account,
+    create_note_from_package, setup_client, sync_with_progress, AccountCreationConfig, ClientSetup,
+    NoteCreationConfig,
 };
 
 use anyhow::{Context, Result};
-use miden_client::{
-    account::StorageMap,
-    transaction::{OutputNote, TransactionRequestBuilder},
-    Felt, Word,
-};
+use miden_client::transaction::{OutputNote, TransactionRequestBuilder};
 use std::{path::Path, sync::Arc};
 
 #[tokio::main]
@@ -17,37 +14,26 @@ async fn main() -> Result<()> {
     let ClientSetup {
         mut client,
         keystore,
+        ..
     } = setup_client().await?;
 
-    let sync_summary = client.sync_state().await?;
+    let sync_summary = sync_with_progress(&mut client, |block_num| {
+        println!("Synced to block {block_num}")
+    })
+    .await?;
     println!("Latest block: {}", sync_summary.block_num);
 
-    // Build contracts
-    let counter_package = Arc::new(
-        build_project_in_dir(Path::new("../contracts/counter-account"), true)
-            .context("Failed to build counter account contract")?,
-    );
+    // Build the increment note contract
     let note_package = Arc::new(
-        build_project_in_dir(Path::new("../contracts/increment-note"), true)
+        build_project_in_dir_async(Path::new("../contracts/increment-note"), true)
+            .await
             .context("Failed to build increment note contract")?,
     );
 
-    // Create the counter account with initial storage and no-auth auth component
-    let count_storage_key = Word::from([Felt::new(0), Felt::new(0), Felt::new(0), Felt::new(1)]);
-    let initial_count = Word::from([Felt::new(0), Felt::new(0), Felt::new(0), Felt::new(0)]);
-    let counter_cfg = AccountCreationConfig {
-        storage_slots: vec![miden_client::account::StorageSlot::Map(
-            StorageMap::with_entries([(count_storage_key, initial_count)])
-                .context("Failed to create storage map with initial counter value")?,
-        )],
-        ..Default::default()
-    };
-
-    // create counter account
-    let counter_account =
-        create_account_from_package(&mut client, counter_package.clone(), counter_cfg)
-            .await
-            .context("Failed to create counter account")?;
+    // Build the counter contract and create the counter account with a count of 0
+    let (counter_account, _counter_package) = create_counter_account(&mut client, 0, None)
+        .await
+        .context("Failed to create counter account")?;
 
     // Create a separate sender account using only the BasicWallet component
     let sender_cfg = AccountCreationConfig::default();
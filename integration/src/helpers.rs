@@ -1,37 +1,304 @@
 //! Common helper functions for scripts and tests
 
-use std::{collections::BTreeSet, path::Path, sync::Arc};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+    future::Future,
+    io::Read,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
 
 use anyhow::{bail, Context, Result};
 use cargo_miden::{run, OutputType};
 use miden_client::{
     account::{
-        component::{AuthRpoFalcon512, BasicWallet, NoAuth},
-        Account, AccountId, AccountStorageMode, AccountType, StorageSlot,
+        component::{AuthRpoFalcon512, BasicFungibleFaucet, BasicWallet, NoAuth},
+        Account, AccountId, AccountStorageMode, AccountType, StorageMap, StorageSlot,
+    },
+    address::{AccountIdAddress, AddressInterface},
+    asset::{Asset, FungibleAsset, TokenSymbol},
+    auth::{
+        AuthSecretKey, AuthenticationError, Signature, SigningInputs, TransactionAuthenticator,
     },
-    auth::AuthSecretKey,
     builder::ClientBuilder,
-    crypto::{FeltRng, SecretKey},
-    keystore::FilesystemKeyStore,
+    crypto::{rpo_falcon512::PublicKey, FeltRng, RpoRandomCoin, SecretKey},
+    keystore::{FilesystemKeyStore, KeyStoreError},
     note::{
-        Note, NoteExecutionHint, NoteInputs, NoteMetadata, NoteRecipient, NoteScript, NoteTag,
-        NoteType,
+        Note, NoteAssets, NoteDetails, NoteExecutionHint, NoteExecutionMode, NoteFile, NoteId,
+        NoteInputs, NoteMetadata, NoteRecipient, NoteScript, NoteTag, NoteType, Nullifier,
     },
-    rpc::{Endpoint, TonicRpcClient},
-    utils::Deserializable,
-    Client, Word,
+    rpc::{domain::account::FetchedAccount, Endpoint, NodeRpcClient, RpcError, TonicRpcClient},
+    store::{sqlite_store::SqliteStore, InputNoteRecord, TransactionFilter},
+    sync::SyncSummary,
+    transaction::{
+        OutputNote, PaymentNoteDescription, TransactionExecutorError, TransactionId,
+        TransactionRequest, TransactionRequestBuilder, TransactionRequestError, TransactionResult,
+        TransactionStatus,
+    },
+    utils::{Deserializable, Serializable},
+    Client, ClientError, ClientRng, DebugMode, Word,
 };
 use miden_core::{Felt, FieldElement};
+use miden_lib::{
+    errors::tx_kernel_errors::{
+        ERR_FUNGIBLE_ASSET_DISTRIBUTE_WOULD_CAUSE_MAX_SUPPLY_TO_BE_EXCEEDED,
+        ERR_VAULT_FUNGIBLE_ASSET_AMOUNT_LESS_THAN_AMOUNT_TO_WITHDRAW,
+        ERR_VAULT_NON_FUNGIBLE_ASSET_TO_REMOVE_NOT_FOUND,
+    },
+    note::create_p2id_note,
+    utils::ScriptBuilder,
+    MidenLib,
+};
 use miden_mast_package::Package;
-use miden_objects::account::{
-    AccountBuilder, AccountComponent, AccountComponentMetadata, AccountComponentTemplate,
+use miden_objects::{
+    account::{
+        delta::{AccountDelta, AccountStorageDelta, AccountVaultDelta},
+        AccountBuilder, AccountComponent, AccountComponentMetadata, AccountComponentTemplate,
+        StorageEntry,
+    },
+    block::BlockNumber,
+    constants::MAX_INPUTS_PER_NOTE,
+    errors::MasmError,
 };
-use rand::{rngs::StdRng, RngCore};
+use miden_processor::ExecutionError;
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+use tokio_util::sync::CancellationToken;
+
+/// Errors returned by the helpers when an input doesn't have the expected shape
+#[derive(Debug, thiserror::Error)]
+pub enum HelperError {
+    #[error("expected a {expected} package, got a {found} package")]
+    UnexpectedPackageKind {
+        expected: PackageKind,
+        found: PackageKind,
+    },
+    #[error(
+        "account {} holds {available} tokens of faucet {}, {required} required",
+        account_id.to_hex(),
+        faucet_id.to_hex()
+    )]
+    InsufficientBalance {
+        account_id: AccountId,
+        faucet_id: AccountId,
+        available: u64,
+        required: u64,
+    },
+    #[error("invalid token symbol `{symbol}`: {reason}")]
+    InvalidTokenSymbol { symbol: String, reason: String },
+    #[error("{} is not a saved package file", path.display())]
+    InvalidPackageHeader { path: PathBuf },
+    #[error("{} uses package file version {found}, expected {expected}", path.display())]
+    UnsupportedPackageVersion {
+        path: PathBuf,
+        found: u16,
+        expected: u16,
+    },
+    #[error("unrecognized {kind} `{value}`, expected one of: {}", valid.join(", "))]
+    UnrecognizedOption {
+        kind: &'static str,
+        value: String,
+        valid: &'static [&'static str],
+    },
+    #[error(
+        "package was linked against {dependency} {}, but the client links {}",
+        found.to_hex(),
+        expected.to_hex()
+    )]
+    IncompatiblePackage {
+        dependency: String,
+        found: Word,
+        expected: Word,
+    },
+    #[error("cargo-miden {found} is linked, {minimum} or newer is required")]
+    OutdatedToolchain { found: String, minimum: String },
+    #[error("note inputs don't encode an account ID: {reason}")]
+    InvalidAccountIdInputs { reason: String },
+    #[error("invalid storage file {}: {reason}", path.display())]
+    InvalidStorageFile { path: PathBuf, reason: String },
+    #[error("package differs from the artifact at {}: {diff:?}", path.display())]
+    ArtifactMismatch { path: PathBuf, diff: PackageDiff },
+    #[error("bytes don't encode a transaction request: {reason}")]
+    InvalidTransactionRequest { reason: String },
+    #[error("keystore can't sign for account {}: {reason}", account_id.to_hex())]
+    SigningKeyMismatch {
+        account_id: AccountId,
+        reason: String,
+    },
+    #[error("package doesn't export a procedure named `{name}`")]
+    MissingProcedure { name: String },
+}
+
+/// Errors returned when a contract fails to build
+#[derive(Debug, thiserror::Error)]
+pub enum BuildError {
+    #[error("compilation failed:\n{}", diagnostics.join("\n"))]
+    Compilation { diagnostics: Vec<String> },
+}
+
+/// Errors returned when an inline MASM script fails to assemble
+#[derive(Debug, thiserror::Error)]
+pub enum ScriptError {
+    /// The rendered assembler report, including the line and column of each error
+    #[error("failed to assemble script:\n{report}")]
+    Assembly { report: String },
+}
+
+/// Errors returned when note parameters exceed protocol limits
+#[derive(Debug, thiserror::Error)]
+pub enum NoteError {
+    #[error("note has {count} inputs, at most {max} are allowed")]
+    TooManyInputs { count: usize, max: usize },
+}
+
+/// Errors returned when an account creation config is inconsistent
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error("account type {account_type:?} is not among the supported types {supported:?}")]
+    UnsupportedAccountType {
+        account_type: AccountType,
+        supported: Vec<AccountType>,
+    },
+    #[error(
+        "storage slots [{}] don't match the layout [{}] declared by the component",
+        got.iter().map(ToString::to_string).collect::<Vec<_>>().join(", "),
+        expected.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+    )]
+    StorageLayoutMismatch {
+        expected: Vec<SlotKind>,
+        got: Vec<SlotKind>,
+    },
+}
+
+/// Errors returned when the client environment can't be set up
+#[derive(Debug, thiserror::Error)]
+pub enum SetupError {
+    #[error("failed to initialize keystore at {}", path.display())]
+    Keystore {
+        path: PathBuf,
+        #[source]
+        source: KeyStoreError,
+    },
+    #[error("failed to open store at {}", path.display())]
+    Store {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error(
+        "store at {} has schema version {found}, expected {expected}; delete it to start with a \
+         fresh store",
+        path.display()
+    )]
+    IncompatibleStore {
+        path: PathBuf,
+        found: u32,
+        expected: u32,
+    },
+    #[error("invalid RPC endpoint `{endpoint}`: {reason}")]
+    InvalidEndpoint { endpoint: String, reason: String },
+    #[error(
+        "no reachable RPC endpoint:{}",
+        failures
+            .iter()
+            .map(|(endpoint, reason)| format!("\n  {endpoint}: {reason}"))
+            .collect::<String>()
+    )]
+    NoReachableEndpoint { failures: Vec<(String, String)> },
+    #[error(
+        "RPC endpoint `{endpoint}` is unreachable ({reason}); check your network connection or \
+         start a local node"
+    )]
+    Unreachable { endpoint: String, reason: String },
+}
+
+/// The kind of artifact contained in a compiled package
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackageKind {
+    /// An executable program with an entrypoint (e.g. a note script)
+    Program,
+    /// A library without account component metadata
+    Library,
+    /// A library carrying account component metadata
+    AccountComponent,
+}
+
+impl fmt::Display for PackageKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PackageKind::Program => write!(f, "program"),
+            PackageKind::Library => write!(f, "library"),
+            PackageKind::AccountComponent => write!(f, "account component"),
+        }
+    }
+}
+
+/// Returns the kind of artifact contained in a compiled package
+///
+/// # Arguments
+/// * `package` - The compiled package to inspect
+///
+/// # Returns
+/// `PackageKind::Program` for executables, `PackageKind::AccountComponent` for libraries
+/// carrying account component metadata and `PackageKind::Library` otherwise
+pub fn package_kind(package: &Package) -> PackageKind {
+    if package.is_program() {
+        PackageKind::Program
+    } else if package.account_component_metadata_bytes.is_some() {
+        PackageKind::AccountComponent
+    } else {
+        PackageKind::Library
+    }
+}
+
+/// Checks that a package contains the expected kind of artifact
+///
+/// An account component is also accepted where a plain library is expected.
+///
+/// # Errors
+/// Returns `HelperError::UnexpectedPackageKind` if the package is of a different kind
+pub fn ensure_package_kind(package: &Package, expected: PackageKind) -> Result<()> {
+    let found = package_kind(package);
+    let compatible = found == expected
+        || (expected == PackageKind::Library && found == PackageKind::AccountComponent);
+
+    if !compatible {
+        return Err(HelperError::UnexpectedPackageKind { expected, found }.into());
+    }
+
+    Ok(())
+}
+
+/// Path of the keystore directory used by `setup_client`, relative to the integration crate
+const KEYSTORE_PATH: &str = "../keystore";
+
+/// Path of the SQLite store used by `setup_client`, relative to the integration crate
+pub const STORE_PATH: &str = "../store.sqlite3";
 
 /// Test setup configuration containing initialized client and keystore
 pub struct ClientSetup {
     pub client: Client<FilesystemKeyStore<StdRng>>,
     pub keystore: Arc<FilesystemKeyStore<StdRng>>,
+    /// The parameters the client was set up with
+    pub params: SetupParams,
+    /// The RPC API the client talks to the node through
+    pub rpc_api: Arc<dyn NodeRpcClient>,
+}
+
+/// Parameters a client was set up with, used to rebuild it on the same node and store
+#[derive(Debug, Clone)]
+pub struct SetupParams {
+    /// The RPC endpoint of the node
+    pub endpoint: Endpoint,
+    /// The keystore directory
+    pub keystore_path: PathBuf,
+    /// The SQLite store
+    pub store_path: PathBuf,
+    /// Whether the client executes transactions in debug mode
+    pub debug_mode: DebugMode,
+    /// Whether the client RNG is a seeded `RpoRandomCoin`, so rebuilt clients are seeded too
+    pub seeded_rng: bool,
 }
 
 /// Initializes test infrastructure with client and keystore
@@ -40,187 +307,942 @@ pub struct ClientSetup {
 /// A `ClientSetup` containing the initialized client and keystore
 ///
 /// # Errors
+/// Returns `SetupError::Unreachable` if the testnet node can't be reached, or an error if
+/// keystore initialization or client building fails
+pub async fn setup_client() -> Result<ClientSetup> {
+    setup_client_with_debug_mode(DebugMode::Enabled).await
+}
+
+/// Initializes test infrastructure with client and keystore using the given debug mode
+///
+/// Debug mode enables VM tracing and richer stack traces at the cost of execution speed,
+/// so performance-sensitive tests should disable it.
+///
+/// # Arguments
+/// * `debug_mode` - Whether the client executes transactions in debug mode
+///
+/// # Returns
+/// A `ClientSetup` containing the initialized client and keystore
+///
+/// # Errors
 /// Returns an error if RPC connection fails, keystore initialization fails,
 /// or client building fails
-pub async fn setup_client() -> Result<ClientSetup> {
-    // Initialize RPC connection
-    let endpoint = Endpoint::testnet();
-    let timeout_ms = 10_000;
-    let rpc_api = Arc::new(TonicRpcClient::new(&endpoint, timeout_ms));
+pub async fn setup_client_with_debug_mode(debug_mode: DebugMode) -> Result<ClientSetup> {
+    setup_client_with(Endpoint::testnet(), Path::new(STORE_PATH), debug_mode, None).await
+}
+
+/// Initializes a client and keystore connected to `endpoint` and backed by the store at
+/// `store_path`, using `rng` as the client RNG if provided
+async fn setup_client_with(
+    endpoint: Endpoint,
+    store_path: &Path,
+    debug_mode: DebugMode,
+    rng: Option<ClientRng>,
+) -> Result<ClientSetup> {
+    setup_client_at(
+        endpoint,
+        Path::new(KEYSTORE_PATH),
+        store_path,
+        debug_mode,
+        rng,
+    )
+    .await
+}
+
+/// Same as `setup_client_with`, with the keystore at `keystore_path`
+async fn setup_client_at(
+    endpoint: Endpoint,
+    keystore_path: &Path,
+    store_path: &Path,
+    debug_mode: DebugMode,
+    rng: Option<ClientRng>,
+) -> Result<ClientSetup> {
+    let params = SetupParams {
+        endpoint: endpoint.clone(),
+        keystore_path: keystore_path.to_path_buf(),
+        store_path: store_path.to_path_buf(),
+        debug_mode,
+        seeded_rng: rng.is_some(),
+    };
 
     // Initialize keystore
-    let keystore_path = std::path::PathBuf::from("../keystore");
+    let keystore = load_or_create_keystore(keystore_path)?;
 
-    let keystore = Arc::new(
-        FilesystemKeyStore::<StdRng>::new(keystore_path)
-            .context("Failed to initialize keystore")?,
-    );
+    let rpc_api: Arc<dyn NodeRpcClient> = Arc::new(TonicRpcClient::new(&endpoint, RPC_TIMEOUT_MS));
+    let client = build_client(
+        &endpoint,
+        rpc_api.clone(),
+        store_path,
+        keystore.clone(),
+        debug_mode,
+        rng,
+    )
+    .await?;
 
-    let store_path = std::path::PathBuf::from("../store.sqlite3");
+    Ok(ClientSetup {
+        client,
+        keystore,
+        params,
+        rpc_api,
+    })
+}
+
+/// Timeout of the client's RPC requests
+const RPC_TIMEOUT_MS: u64 = 10_000;
+
+/// Builds a client signing with `authenticator`, connected to `endpoint` through `rpc_api` and
+/// backed by the store at `store_path`
+///
+/// The store's schema is checked before the client opens it, and the node is queried once so an
+/// unreachable endpoint is reported right away.
+async fn build_client<A>(
+    endpoint: &Endpoint,
+    rpc_api: Arc<dyn NodeRpcClient>,
+    store_path: &Path,
+    authenticator: Arc<A>,
+    debug_mode: DebugMode,
+    rng: Option<ClientRng>,
+) -> Result<Client<A>>
+where
+    A: TransactionAuthenticator + Send + Sync + 'static,
+{
     let store_path_str = store_path
         .to_str()
         .context("Store path contains invalid UTF-8")?;
 
-    let client = ClientBuilder::new()
-        .rpc(rpc_api)
+    check_store_schema(store_path).await?;
+
+    // Surface an unwritable store location before the client tries to open it
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(store_path)
+        .map_err(|source| SetupError::Store {
+            path: store_path.to_path_buf(),
+            source,
+        })?;
+
+    let mut builder = ClientBuilder::new()
+        .rpc(rpc_api.clone())
         .sqlite_store(store_path_str)
-        .authenticator(keystore.clone())
-        .in_debug_mode(true.into())
+        .authenticator(authenticator)
+        .in_debug_mode(debug_mode);
+    if let Some(rng) = rng {
+        builder = builder.rng(rng);
+    }
+
+    let client = builder
         .build()
         .await
         .context("Failed to build Miden client")?;
 
-    Ok(ClientSetup { client, keystore })
+    // Building the client doesn't connect yet, so probe the node to fail early when offline
+    if let Err(err) = rpc_api.get_block_header_by_number(None, false).await {
+        if is_connection_failure(&err) {
+            return Err(SetupError::Unreachable {
+                endpoint: endpoint.to_string(),
+                reason: err.to_string(),
+            }
+            .into());
+        }
+        return Err(err).context(format!("Failed to query RPC endpoint {endpoint}"));
+    }
+
+    Ok(client)
 }
 
-/// Builds a Miden project in the specified directory
+/// Rebuilds a client from the parameters of a previous setup
+///
+/// A seeded client RNG is re-seeded with `seed`, so the rebuilt client stays reproducible
+/// without replaying the serial numbers the previous client already drew.
+async fn setup_client_from_params(params: &SetupParams, seed: Option<Word>) -> Result<ClientSetup> {
+    let rng = seed.map(|seed| ClientRng::new(Box::new(RpoRandomCoin::new(seed))));
+    setup_client_at(
+        params.endpoint.clone(),
+        &params.keystore_path,
+        &params.store_path,
+        params.debug_mode,
+        rng,
+    )
+    .await
+}
+
+/// Returns whether an RPC error means the node couldn't be reached at all, e.g. because the
+/// connection was refused or the host name didn't resolve
+///
+/// That's the case for transport errors raised while connecting, and for requests the node
+/// answered with the gRPC status `UNAVAILABLE`.
+fn is_connection_failure(err: &RpcError) -> bool {
+    if matches!(err, RpcError::ConnectionError(_)) {
+        return true;
+    }
+
+    let mut cause = std::error::Error::source(err);
+    while let Some(error) = cause {
+        if error
+            .downcast_ref::<tonic::Status>()
+            .is_some_and(|status| status.code() == tonic::Code::Unavailable)
+        {
+            return true;
+        }
+        cause = error.source();
+    }
+    false
+}
+
+/// Opens the keystore directory at `path`, creating it if it doesn't exist
+///
+/// Keys already in the directory are kept, so a script can be re-run and still sign for the
+/// accounts it created in previous runs.
+///
+/// # Errors
+/// Returns `SetupError::Keystore` if the directory can't be created or read
+pub fn load_or_create_keystore(path: &Path) -> Result<Arc<FilesystemKeyStore<StdRng>>> {
+    let keystore = FilesystemKeyStore::<StdRng>::new(path.to_path_buf()).map_err(|source| {
+        SetupError::Keystore {
+            path: path.to_path_buf(),
+            source,
+        }
+    })?;
+
+    Ok(Arc::new(keystore))
+}
+
+/// Initializes test infrastructure whose client RNG is an `RpoRandomCoin` seeded with `seed`
+///
+/// The client RNG (`client.rng()`) feeds note serial numbers (`draw_word`), account init seeds
+/// and key generation in the helpers, so seeding it makes note IDs reproducible across runs.
+/// The `StdRng` of the `FilesystemKeyStore` is separate and only used by the keystore when
+/// signing.
 ///
 /// # Arguments
-/// * `dir` - Path to the directory containing the Cargo.toml
-/// * `release` - Whether to build in release mode
+/// * `seed` - The seed of the random coin
 ///
 /// # Returns
-/// The compiled `Package`
+/// A `ClientSetup` containing the initialized client and keystore
 ///
 /// # Errors
-/// Returns an error if compilation fails or if the output is not in the expected format
-pub fn build_project_in_dir(dir: &Path, release: bool) -> Result<Package> {
-    let profile = if release { "--release" } else { "--debug" };
-    let manifest_path = dir.join("Cargo.toml");
-    let manifest_arg = manifest_path.to_string_lossy();
+/// Returns an error if RPC connection fails, keystore initialization fails,
+/// or client building fails
+pub async fn setup_client_with_coin(seed: Word) -> Result<ClientSetup> {
+    let coin = RpoRandomCoin::new(seed);
+    setup_client_with(
+        Endpoint::testnet(),
+        Path::new(STORE_PATH),
+        DebugMode::Enabled,
+        Some(ClientRng::new(Box::new(coin))),
+    )
+    .await
+}
 
-    let args = vec![
-        "cargo",
-        "miden",
-        "build",
-        profile,
-        "--manifest-path",
-        &manifest_arg,
-    ];
+/// Path used by SQLite for in-memory databases
+const IN_MEMORY_STORE_PATH: &str = ":memory:";
 
-    let output = run(args.into_iter().map(String::from), OutputType::Masm)
-        .context("Failed to compile project")?
-        .context("Cargo miden build returned None")?;
+/// Offset of the `user_version` field in the header of a SQLite database file
+const SQLITE_USER_VERSION_OFFSET: usize = 60;
 
-    let artifact_path = match output {
-        cargo_miden::CommandOutput::BuildCommandOutput { output } => match output {
-            cargo_miden::BuildOutput::Masm { artifact_path } => artifact_path,
-            other => bail!("Expected Masm output, got {:?}", other),
-        },
-        other => bail!("Expected BuildCommandOutput, got {:?}", other),
-    };
+/// Reads the schema version SQLite keeps in the header of a database file
+///
+/// Returns `None` if the file is too short to be a SQLite database (e.g. it's empty).
+fn read_sqlite_user_version(path: &Path) -> Result<Option<u32>> {
+    let mut header = [0_u8; SQLITE_USER_VERSION_OFFSET + 4];
+    let mut file =
+        std::fs::File::open(path).context(format!("Failed to open {}", path.display()))?;
 
-    let package_bytes = std::fs::read(&artifact_path).context(format!(
-        "Failed to read compiled package from {}",
-        artifact_path.display()
-    ))?;
+    match file.read_exact(&mut header) {
+        Ok(()) => {}
+        Err(err) if err.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(err) => return Err(err).context(format!("Failed to read {}", path.display())),
+    }
 
-    Package::read_from_bytes(&package_bytes).context("Failed to deserialize package from bytes")
+    let version_bytes = &header[SQLITE_USER_VERSION_OFFSET..];
+    Ok(Some(u32::from_be_bytes(version_bytes.try_into()?)))
 }
 
-/// Configuration for creating an account with a custom component
-#[derive(Clone)]
-pub struct AccountCreationConfig {
-    pub account_type: AccountType,
-    pub storage_mode: AccountStorageMode,
-    pub storage_slots: Vec<StorageSlot>,
-    pub supported_types: Option<Vec<AccountType>>,
+/// Schema version of stores created by the linked `miden-client`, computed on first use
+static EXPECTED_STORE_VERSION: tokio::sync::OnceCell<u32> = tokio::sync::OnceCell::const_new();
+
+/// Returns the schema version the linked `miden-client` writes to new stores
+///
+/// The version is read from a scratch store the first time it's needed and cached for the rest
+/// of the process, so repeated client setups don't each create and delete a store.
+///
+/// # Errors
+/// Returns an error if the scratch store can't be created or read
+async fn expected_store_version() -> Result<u32> {
+    EXPECTED_STORE_VERSION
+        .get_or_try_init(|| async {
+            let scratch_path = std::env::temp_dir().join(format!(
+                "miden-store-schema-{}.sqlite3",
+                rand::random::<u64>()
+            ));
+            let expected = SqliteStore::new(scratch_path.clone())
+                .await
+                .map(drop)
+                .context("Failed to create scratch store")
+                .and_then(|()| read_sqlite_user_version(&scratch_path));
+            let _ = std::fs::remove_file(&scratch_path);
+            expected?.context("Scratch store has no SQLite header")
+        })
+        .await
+        .copied()
 }
 
-impl Default for AccountCreationConfig {
-    fn default() -> Self {
-        Self {
-            account_type: AccountType::RegularAccountImmutableCode,
-            storage_mode: AccountStorageMode::Public,
-            storage_slots: vec![],
-            supported_types: None,
+/// Checks that an existing store was created with the schema of the linked `miden-client`
+///
+/// The expected schema version is taken from a scratch store created once by the current client, so
+/// a store left over from an older client version is detected before `ClientBuilder::build`
+/// fails on it. The check is skipped for in-memory stores and stores that don't exist yet.
+///
+/// # Arguments
+/// * `store_path` - Path of the SQLite store
+///
+/// # Errors
+/// Returns `SetupError::IncompatibleStore` if the schema versions differ, or an error if the
+/// store files can't be read
+pub async fn check_store_schema(store_path: &Path) -> Result<()> {
+    if store_path == Path::new(IN_MEMORY_STORE_PATH) || !store_path.exists() {
+        return Ok(());
+    }
+
+    let Some(found) = read_sqlite_user_version(store_path)? else {
+        return Ok(());
+    };
+
+    let expected = expected_store_version().await?;
+    if found != expected {
+        return Err(SetupError::IncompatibleStore {
+            path: store_path.to_path_buf(),
+            found,
+            expected,
         }
+        .into());
     }
+
+    Ok(())
 }
 
-/// Creates an account component from a compiled package
+/// Environment variable holding the RPC endpoint used by `setup_from_env`
+pub const ENDPOINT_ENV_VAR: &str = "MIDEN_ENDPOINT";
+
+/// Environment variable holding the store path used by `setup_from_env`
+pub const STORE_PATH_ENV_VAR: &str = "MIDEN_STORE_PATH";
+
+/// Initializes a client whose network and store are configured through environment variables
 ///
-/// # Arguments
-/// * `package` - The compiled package containing account component metadata
-/// * `config` - Configuration for account creation
+/// `MIDEN_ENDPOINT` selects the RPC endpoint (e.g. `http://localhost:57291`) and defaults to
+/// testnet, while `MIDEN_STORE_PATH` selects the SQLite store and defaults to the same store as
+/// `setup_client`. This lets the same binary run against a local node or testnet without
+/// recompiling.
 ///
 /// # Returns
-/// An `AccountComponent` configured according to the provided config
+/// A `ClientSetup` containing the initialized client and keystore
 ///
 /// # Errors
-/// Returns an error if the package doesn't contain account component metadata or deserialization fails
-pub fn account_component_from_package(
-    package: Arc<Package>,
-    config: &AccountCreationConfig,
-) -> Result<AccountComponent> {
-    let bytes = package
-        .account_component_metadata_bytes
-        .as_deref()
-        .context("Package missing account component metadata")?;
+/// Returns `SetupError::InvalidEndpoint` if `MIDEN_ENDPOINT` can't be parsed, or an error if
+/// keystore initialization or client building fails
+pub async fn setup_from_env() -> Result<ClientSetup> {
+    let endpoint = match std::env::var(ENDPOINT_ENV_VAR) {
+        Ok(endpoint) => {
+            Endpoint::try_from(endpoint.as_str()).map_err(|reason| SetupError::InvalidEndpoint {
+                endpoint: endpoint.clone(),
+                reason: reason.to_string(),
+            })?
+        }
+        Err(_) => Endpoint::testnet(),
+    };
 
-    let metadata = AccountComponentMetadata::read_from_bytes(bytes)
-        .context("Failed to deserialize account component metadata")?;
+    let store_path = std::env::var(STORE_PATH_ENV_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from(STORE_PATH));
 
-    let library = package.unwrap_library();
-    let template = AccountComponentTemplate::new(metadata, library.as_ref().clone());
+    setup_client_with(endpoint, &store_path, DebugMode::Enabled, None).await
+}
 
-    let component = AccountComponent::new(template.library().clone(), config.storage_slots.clone())
-        .context("Failed to create account component")?;
+/// Initializes a client connected to the first reachable endpoint of `endpoints`
+///
+/// Endpoints are tried in order, and an endpoint is considered reachable if it serves the
+/// latest block header. This lets scripts prefer a local node and fall back to testnet.
+///
+/// # Arguments
+/// * `endpoints` - The RPC endpoints to try, in order of preference
+/// * `debug_mode` - Whether the client executes transactions in debug mode
+///
+/// # Returns
+/// A `ClientSetup` containing the initialized client and keystore
+///
+/// # Errors
+/// Returns `SetupError::NoReachableEndpoint` listing every endpoint and why it failed, or an
+/// error if keystore initialization or client building fails
+pub async fn setup_client_multi(
+    endpoints: Vec<Endpoint>,
+    debug_mode: DebugMode,
+) -> Result<ClientSetup> {
+    let mut failures = Vec::with_capacity(endpoints.len());
 
-    // Use supported types from config if provided, otherwise default to RegularAccountImmutableCode
-    let supported_types = if let Some(types) = &config.supported_types {
-        BTreeSet::from_iter(types.clone())
-    } else {
-        BTreeSet::from_iter([AccountType::RegularAccountImmutableCode])
-    };
+    for endpoint in endpoints {
+        let rpc_api = TonicRpcClient::new(&endpoint, ENDPOINT_HEALTH_CHECK_TIMEOUT_MS);
+        match rpc_api.get_block_header_by_number(None, false).await {
+            Ok(_) => {
+                return setup_client_with(endpoint, Path::new(STORE_PATH), debug_mode, None).await
+            }
+            Err(err) => failures.push((endpoint.to_string(), err.to_string())),
+        }
+    }
 
-    Ok(component.with_supported_types(supported_types))
+    Err(SetupError::NoReachableEndpoint { failures }.into())
 }
 
-/// Creates an account with a custom component from a compiled package
+/// Timeout of the request checking that an endpoint is reachable
+const ENDPOINT_HEALTH_CHECK_TIMEOUT_MS: u64 = 5_000;
+
+/// Builds a Miden project in the specified directory
+///
+/// Builds started from several threads are serialized, so they never contend for cargo's
+/// target directory lock.
 ///
 /// # Arguments
-/// * `client` - The Miden client instance
-/// * `package` - The compiled package containing the account component
-/// * `config` - Configuration for account creation
+/// * `dir` - Path to the directory containing the Cargo.toml
+/// * `release` - Whether to build in release mode
 ///
 /// # Returns
-/// The created `Account`
+/// The compiled `Package`
 ///
 /// # Errors
-/// Returns an error if account creation or client operations fail
-pub async fn create_account_from_package(
-    client: &mut Client<FilesystemKeyStore<StdRng>>,
-    package: Arc<Package>,
-    config: AccountCreationConfig,
-) -> Result<Account> {
-    let account_component = account_component_from_package(package, &config)
-        .context("Failed to create account component from package")?;
+/// Returns an error if compilation fails or if the output is not in the expected format
+pub fn build_project_in_dir(dir: &Path, release: bool) -> Result<Package> {
+    build_project_with_diagnostics(dir, release).map(CompiledPackage::into_package)
+}
 
-    let mut init_seed = [0_u8; 32];
-    client.rng().fill_bytes(&mut init_seed);
+/// Builds a Miden project in the specified directory without blocking the async runtime
+///
+/// The synchronous build runs on tokio's blocking thread pool, so the runtime stays responsive
+/// while it compiles. Builds awaited concurrently are safe, but run one at a time.
+///
+/// # Arguments
+/// * `dir` - Path to the directory containing the Cargo.toml
+/// * `release` - Whether to build in release mode
+///
+/// # Returns
+/// The compiled `Package`
+///
+/// # Errors
+/// Returns an error if compilation fails or if the output is not in the expected format
+pub async fn build_project_in_dir_async(dir: &Path, release: bool) -> Result<Package> {
+    let dir = dir.to_path_buf();
+    tokio::task::spawn_blocking(move || build_project_in_dir(&dir, release))
+        .await
+        .context("Build task panicked")?
+}
 
-    let (account, seed) = AccountBuilder::new(init_seed)
-        .account_type(config.account_type)
-        .storage_mode(config.storage_mode)
-        .with_component(account_component)
-        .with_auth_component(NoAuth)
-        .build()
-        .context("Failed to build account")?;
+/// A compiled package together with the warnings emitted while building it
+#[derive(Debug)]
+pub struct CompiledPackage {
+    package: Package,
+    warnings: Vec<String>,
+}
 
-    println!("Account ID: {:?}", account.id());
+impl CompiledPackage {
+    /// Returns the compiled package
+    pub fn package(&self) -> &Package {
+        &self.package
+    }
 
-    client
-        .add_account(&account, Some(seed), false)
-        .await
-        .context("Failed to add account to client")?;
+    /// Consumes the build result and returns the compiled package
+    pub fn into_package(self) -> Package {
+        self.package
+    }
 
-    Ok(account)
+    /// Returns the warning lines emitted by the compiler
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
 }
 
-pub async fn create_testing_account_from_package(
-    package: Arc<Package>,
-    config: AccountCreationConfig,
+/// Builds a Miden project in the specified directory and collects compiler diagnostics
+///
+/// The warnings are collected by running `cargo check` on the project in a child process, so
+/// the compiler output of the build itself still goes to stderr.
+///
+/// # Arguments
+/// * `dir` - Path to the directory containing the Cargo.toml
+/// * `release` - Whether to build in release mode
+///
+/// # Returns
+/// The compiled package and the warnings emitted while building it
+///
+/// # Errors
+/// Returns `BuildError::Compilation` with the compiler's error lines if compilation fails, or an
+/// error if the output is not in the expected format
+pub fn build_project_with_diagnostics(dir: &Path, release: bool) -> Result<CompiledPackage> {
+    let options = BuildOptions::profile(release);
+    let output = run_miden_build(dir, &options, false, OutputType::Masm)?;
+    let package = read_package(&masm_artifact_path(output)?)?;
+    let _guard = BUILD_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    let warnings = cargo_diagnostics(dir, &options, false)?.warnings;
+    Ok(CompiledPackage { package, warnings })
+}
+
+/// Builds a Miden project with verbose cargo output
+///
+/// Same as `build_project_in_dir`, but if compilation fails the returned
+/// `BuildError::Compilation` holds the complete `-vv` output of checking the project instead of
+/// only its error lines.
+///
+/// # Arguments
+/// * `dir` - Path to the directory containing the Cargo.toml
+/// * `release` - Whether to build in release mode
+///
+/// # Returns
+/// The compiled `Package`
+///
+/// # Errors
+/// Returns `BuildError::Compilation` with the full `-vv` output of `cargo check` and the build
+/// report if compilation fails, or an
+/// error if the output is not in the expected format
+pub fn build_project_verbose(dir: &Path, release: bool) -> Result<Package> {
+    let output = run_miden_build(dir, &BuildOptions::profile(release), true, OutputType::Masm)?;
+    read_package(&masm_artifact_path(output)?)
+}
+
+/// An artifact emitted by `cargo miden build`
+#[derive(Debug)]
+pub enum BuildArtifact {
+    /// The WebAssembly component compiled from the crate
+    Wasm { artifact_path: PathBuf },
+    /// The Miden package compiled from the WebAssembly component
+    Masm {
+        artifact_path: PathBuf,
+        package: Package,
+    },
+}
+
+impl BuildArtifact {
+    /// Returns the path cargo-miden wrote the artifact to
+    pub fn artifact_path(&self) -> &Path {
+        match self {
+            BuildArtifact::Wasm { artifact_path } | BuildArtifact::Masm { artifact_path, .. } => {
+                artifact_path
+            }
+        }
+    }
+
+    /// Returns the compiled package if the artifact is a Miden package
+    pub fn package(&self) -> Option<&Package> {
+        match self {
+            BuildArtifact::Wasm { .. } => None,
+            BuildArtifact::Masm { package, .. } => Some(package),
+        }
+    }
+}
+
+/// Builds a Miden project and returns every artifact the build emits
+///
+/// The project is built once per output type of `cargo miden build`, and each reported output
+/// is collected with its artifact path. The Miden package is compiled from the WebAssembly
+/// component, so its build reuses the component cargo compiled for the first one.
+///
+/// # Arguments
+/// * `dir` - Path to the directory containing the Cargo.toml
+/// * `release` - Whether to build in release mode
+///
+/// # Returns
+/// The WebAssembly component followed by the Miden package
+///
+/// # Errors
+/// Returns `BuildError::Compilation` with the compiler's error lines if compilation fails, or an
+/// error if an emitted package can't be read
+pub fn build_all_outputs(dir: &Path, release: bool) -> Result<Vec<BuildArtifact>> {
+    let options = BuildOptions::profile(release);
+
+    [OutputType::Wasm, OutputType::Masm]
+        .into_iter()
+        .map(|output_type| {
+            let output = run_miden_build(dir, &options, false, output_type)?;
+            match output {
+                cargo_miden::BuildOutput::Wasm { artifact_path, .. } => {
+                    Ok(BuildArtifact::Wasm { artifact_path })
+                }
+                cargo_miden::BuildOutput::Masm { artifact_path } => {
+                    let package = read_package(&artifact_path)?;
+                    Ok(BuildArtifact::Masm {
+                        artifact_path,
+                        package,
+                    })
+                }
+            }
+        })
+        .collect()
+}
+
+/// Options of a contract build beyond its profile
+#[derive(Debug, Clone, Default)]
+pub struct BuildOptions {
+    /// Whether to build in release mode
+    pub release: bool,
+    /// Cargo features to enable, e.g. a feature gating test-only procedures
+    pub features: Vec<String>,
+    /// Whether to disable the crate's default features
+    pub no_default_features: bool,
+    /// Minimum version of the linked `cargo-miden` the build requires, e.g. "0.4.0"
+    pub min_cargo_miden_version: Option<String>,
+}
+
+impl BuildOptions {
+    /// Returns options building with the crate's default features only
+    pub fn profile(release: bool) -> Self {
+        Self {
+            release,
+            ..Default::default()
+        }
+    }
+}
+
+/// Builds a Miden project with custom build options, e.g. to enable Cargo features
+///
+/// # Arguments
+/// * `dir` - Path to the directory containing the Cargo.toml
+/// * `options` - The profile and features to build with
+///
+/// # Returns
+/// The compiled `Package`
+///
+/// # Errors
+/// Returns `HelperError::OutdatedToolchain` if the linked `cargo-miden` is older than
+/// `options.min_cargo_miden_version`, `BuildError::Compilation` with the compiler's error lines if
+/// compilation fails, or an error if the output is not in the expected format
+pub fn build_project_with_options(dir: &Path, options: &BuildOptions) -> Result<Package> {
+    let output = run_miden_build(dir, options, false, OutputType::Masm)?;
+    read_package(&masm_artifact_path(output)?)
+}
+
+/// Runs `cargo miden build` for the project in `dir`, emitting `output_type`
+///
+/// Returns the build output reported by cargo-miden. If the build fails, the compiler's
+/// diagnostics are collected by `cargo_diagnostics`.
+fn run_miden_build(
+    dir: &Path,
+    options: &BuildOptions,
+    verbose: bool,
+    output_type: OutputType,
+) -> Result<cargo_miden::BuildOutput> {
+    // Builds are serialized rather than retried on contention: concurrent builds of projects
+    // sharing a target directory would block on cargo's file lock. A panicked build leaves
+    // nothing behind that needs cleaning up, so a poisoned lock is still usable.
+    let _guard = BUILD_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+
+    if let Some(minimum) = &options.min_cargo_miden_version {
+        ensure_cargo_miden_version(minimum)?;
+    }
+
+    let profile = if options.release {
+        "--release"
+    } else {
+        "--debug"
+    };
+    let manifest_path = dir.join("Cargo.toml");
+    let manifest_arg = manifest_path.to_string_lossy();
+    let features_arg = options.features.join(",");
+
+    let mut args = vec![
+        "cargo",
+        "miden",
+        "build",
+        profile,
+        "--manifest-path",
+        &manifest_arg,
+    ];
+    if !options.features.is_empty() {
+        args.extend(["--features", &features_arg]);
+    }
+    if options.no_default_features {
+        args.push("--no-default-features");
+    }
+    if verbose {
+        args.push("-vv");
+    }
+
+    let output = match run(args.into_iter().map(String::from), output_type) {
+        Ok(Some(output)) => output,
+        Ok(None) => bail!("Cargo miden build returned None"),
+        Err(report) => {
+            let report = format!("{report:?}");
+            // The report only summarizes the failure, the compiler errors come from cargo
+            let checked = cargo_diagnostics(dir, options, verbose).ok();
+            let mut diagnostics = match checked {
+                Some(checked) if verbose => checked.output,
+                Some(checked) => checked.errors,
+                None => Vec::new(),
+            };
+            diagnostics.extend(report.lines().map(str::to_owned));
+            return Err(BuildError::Compilation { diagnostics }.into());
+        }
+    };
+
+    match output {
+        cargo_miden::CommandOutput::BuildCommandOutput { output } => Ok(output),
+        other => bail!("Expected BuildCommandOutput, got {:?}", other),
+    }
+}
+
+/// Held for the duration of every `cargo miden build` run by the helpers
+static BUILD_LOCK: Mutex<()> = Mutex::new(());
+
+/// Target cargo-miden compiles contracts to
+const MIDEN_BUILD_TARGET: &str = "wasm32-wasip2";
+
+/// Compiler diagnostics reported by `cargo check`
+struct CargoDiagnostics {
+    /// Error messages, one line per diagnostic
+    errors: Vec<String>,
+    /// Warning messages, one line per diagnostic
+    warnings: Vec<String>,
+    /// The rendered diagnostics followed by cargo's own stderr output
+    output: Vec<String>,
+}
+
+/// Checks the project in `dir` with cargo's JSON message format and collects its diagnostics
+///
+/// cargo runs in a child process whose output is piped back, so the diagnostics are collected
+/// without redirecting this process' stderr, which other threads may be writing to.
+fn cargo_diagnostics(
+    dir: &Path,
+    options: &BuildOptions,
+    verbose: bool,
+) -> Result<CargoDiagnostics> {
+    let mut command = std::process::Command::new("cargo");
+    command
+        .current_dir(dir)
+        .arg("check")
+        .arg("--manifest-path")
+        .arg(dir.join("Cargo.toml"))
+        .args(["--target", MIDEN_BUILD_TARGET, "--message-format=json"]);
+    if options.release {
+        command.arg("--release");
+    }
+    if !options.features.is_empty() {
+        command.args(["--features", &options.features.join(",")]);
+    }
+    if options.no_default_features {
+        command.arg("--no-default-features");
+    }
+    if verbose {
+        command.arg("-vv");
+    }
+
+    let output = command
+        .output()
+        .context(format!("Failed to run `cargo check` in {}", dir.display()))?;
+
+    let mut diagnostics = CargoDiagnostics {
+        errors: Vec::new(),
+        warnings: Vec::new(),
+        output: Vec::new(),
+    };
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        let Ok(message) = serde_json::from_str::<serde_json::Value>(line) else {
+            continue;
+        };
+        if message["reason"] != "compiler-message" {
+            continue;
+        }
+
+        let message = &message["message"];
+        let level = message["level"].as_str().unwrap_or_default();
+        let text = message["message"].as_str().unwrap_or_default();
+        match level {
+            "error" => diagnostics.errors.push(format!("error: {text}")),
+            "warning" => diagnostics.warnings.push(format!("warning: {text}")),
+            _ => {}
+        }
+        if let Some(rendered) = message["rendered"].as_str() {
+            diagnostics
+                .output
+                .extend(rendered.lines().map(str::to_owned));
+        }
+    }
+    diagnostics.output.extend(
+        String::from_utf8_lossy(&output.stderr)
+            .lines()
+            .map(str::to_owned),
+    );
+
+    Ok(diagnostics)
+}
+
+/// Returns the path of the Miden package a build emitted
+fn masm_artifact_path(output: cargo_miden::BuildOutput) -> Result<PathBuf> {
+    match output {
+        cargo_miden::BuildOutput::Masm { artifact_path } => Ok(artifact_path),
+        other => bail!("Expected Masm output, got {:?}", other),
+    }
+}
+
+/// Reads and deserializes a compiled package from disk
+fn read_package(artifact_path: &Path) -> Result<Package> {
+    let package_bytes = std::fs::read(artifact_path).context(format!(
+        "Failed to read compiled package from {}",
+        artifact_path.display()
+    ))?;
+
+    Package::read_from_bytes(&package_bytes).context("Failed to deserialize package from bytes")
+}
+
+/// Configuration for creating an account with a custom component
+#[derive(Clone)]
+pub struct AccountCreationConfig {
+    pub account_type: AccountType,
+    pub storage_mode: AccountStorageMode,
+    pub storage_slots: Vec<StorageSlot>,
+    pub supported_types: Option<Vec<AccountType>>,
+}
+
+impl Default for AccountCreationConfig {
+    fn default() -> Self {
+        Self {
+            account_type: AccountType::RegularAccountImmutableCode,
+            storage_mode: AccountStorageMode::Public,
+            storage_slots: vec![],
+            supported_types: None,
+        }
+    }
+}
+
+impl AccountCreationConfig {
+    /// Returns a config for creating a public fungible faucet with `create_fungible_faucet_account`
+    pub fn faucet() -> Self {
+        Self {
+            account_type: AccountType::FungibleFaucet,
+            storage_mode: AccountStorageMode::Public,
+            storage_slots: vec![],
+            supported_types: Some(vec![AccountType::FungibleFaucet]),
+        }
+    }
+
+    /// Returns the account types the component supports
+    ///
+    /// Defaults to `RegularAccountImmutableCode` when `supported_types` is `None`.
+    ///
+    /// # Errors
+    /// Returns `ConfigError::UnsupportedAccountType` if the set is empty or doesn't include
+    /// `account_type`
+    pub fn validated_supported_types(&self) -> Result<BTreeSet<AccountType>> {
+        // Use supported types from config if provided, otherwise default to RegularAccountImmutableCode
+        let supported_types = if let Some(types) = &self.supported_types {
+            BTreeSet::from_iter(types.clone())
+        } else {
+            BTreeSet::from_iter([AccountType::RegularAccountImmutableCode])
+        };
+
+        if !supported_types.contains(&self.account_type) {
+            return Err(ConfigError::UnsupportedAccountType {
+                account_type: self.account_type,
+                supported: supported_types.into_iter().collect(),
+            }
+            .into());
+        }
+
+        Ok(supported_types)
+    }
+}
+
+/// Creates an account component from a compiled package
+///
+/// # Arguments
+/// * `package` - The compiled package containing account component metadata
+/// * `config` - Configuration for account creation
+///
+/// # Returns
+/// An `AccountComponent` configured according to the provided config
+///
+/// # Errors
+/// Returns an error if the package isn't an account component, deserialization fails, the
+/// config's storage slots don't match the component's declared layout
+/// (`ConfigError::StorageLayoutMismatch`), or the config's supported types don't include its
+/// account type
+pub fn account_component_from_package(
+    package: Arc<Package>,
+    config: &AccountCreationConfig,
+) -> Result<AccountComponent> {
+    let metadata = component_metadata(&package)?;
+    validate_storage_layout(&metadata, &config.storage_slots)?;
+    account_component_from_metadata(metadata, &package, config)
+}
+
+/// Deserializes the account component metadata embedded in a compiled package
+fn component_metadata(package: &Package) -> Result<AccountComponentMetadata> {
+    ensure_package_kind(package, PackageKind::AccountComponent)?;
+
+    let bytes = package
+        .account_component_metadata_bytes
+        .as_deref()
+        .context("Package missing account component metadata")?;
+
+    AccountComponentMetadata::read_from_bytes(bytes)
+        .context("Failed to deserialize account component metadata")
+}
+
+/// Creates an account component from component metadata and the library of a compiled package
+fn account_component_from_metadata(
+    metadata: AccountComponentMetadata,
+    package: &Package,
+    config: &AccountCreationConfig,
+) -> Result<AccountComponent> {
+    let library = package.unwrap_library();
+    let template = AccountComponentTemplate::new(metadata, library.as_ref().clone());
+
+    let component = AccountComponent::new(template.library().clone(), config.storage_slots.clone())
+        .context("Failed to create account component")?;
+
+    let supported_types = config.validated_supported_types()?;
+
+    Ok(component.with_supported_types(supported_types))
+}
+
+/// Creates an account with a custom component from a compiled package
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `package` - The compiled package containing the account component
+/// * `config` - Configuration for account creation
+///
+/// # Returns
+/// The created `Account`
+///
+/// # Errors
+/// Returns an error if account creation or client operations fail
+pub async fn create_account_from_package(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    package: Arc<Package>,
+    config: AccountCreationConfig,
+) -> Result<Account> {
+    let account_component = account_component_from_package(package, &config)
+        .context("Failed to create account component from package")?;
+
+    let mut init_seed = [0_u8; 32];
+    client.rng().fill_bytes(&mut init_seed);
+
+    let (account, seed) = AccountBuilder::new(init_seed)
+        .account_type(config.account_type)
+        .storage_mode(config.storage_mode)
+        .with_component(account_component)
+        .with_auth_component(NoAuth)
+        .build()
+        .context("Failed to build account")?;
+
+    println!("Account ID: {:?}", account.id());
+
+    client
+        .add_account(&account, Some(seed), false)
+        .await
+        .context("Failed to add account to client")?;
+
+    Ok(account)
+}
+
+pub async fn create_testing_account_from_package(
+    package: Arc<Package>,
+    config: AccountCreationConfig,
 ) -> Result<Account> {
     let account_component = account_component_from_package(package, &config)
         .context("Failed to create account component from package")?;
@@ -238,6 +1260,8 @@ pub async fn create_testing_account_from_package(
 
 /// Configuration for creating a note
 pub struct NoteCreationConfig {
+    /// Visibility of the note. Public notes are stored on-chain, while only the header of
+    /// private notes is; their details must be shared out of band (see `publish_private_note`)
     pub note_type: NoteType,
     pub tag: NoteTag,
     pub assets: miden_client::note::NoteAssets,
@@ -261,8 +1285,68 @@ impl Default for NoteCreationConfig {
     }
 }
 
+impl NoteCreationConfig {
+    /// Returns a config for a note that is expected to be consumed after block `block_num`
+    ///
+    /// Execution hints are advisory: they tell consumers when a note becomes consumable but are
+    /// not enforced by the transaction kernel, so the note script must check the block height
+    /// itself if consumption before `block_num` has to fail.
+    ///
+    /// # Errors
+    /// Returns an error if `block_num` can't be encoded in an execution hint
+    pub fn after_block(block_num: u32) -> Result<Self> {
+        let execution_hint = NoteExecutionHint::after_block(block_num.into())
+            .context("Failed to create after-block execution hint")?;
+
+        Ok(Self {
+            execution_hint,
+            ..Default::default()
+        })
+    }
+
+    /// Returns a config for a note that is expected to be consumed in a recurring block slot
+    ///
+    /// The note is consumable during the `slot_offset`-th slot of every round, where a round is
+    /// `2^round_len` blocks and a slot is `2^slot_len` blocks.
+    pub fn on_block_slot(round_len: u8, slot_len: u8, slot_offset: u8) -> Self {
+        Self {
+            execution_hint: NoteExecutionHint::on_block_slot(round_len, slot_len, slot_offset),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the config with its assets replaced by the ones built by `assets`
+    ///
+    /// # Errors
+    /// Returns an error if the assets fail to build
+    pub fn with_assets(mut self, assets: NoteAssetsBuilder) -> Result<Self> {
+        self.assets = assets.build()?;
+        Ok(self)
+    }
+
+    /// Checks that the tag, note type and execution hint can be combined
+    ///
+    /// # Errors
+    /// Returns an error if the tag targets network execution while the note is private or
+    /// carries no execution hint for the network to schedule it by
+    pub fn validate(&self) -> Result<()> {
+        if self.tag.execution_mode() == NoteExecutionMode::Network {
+            if self.note_type != NoteType::Public {
+                bail!("Notes with a network execution tag must be public");
+            }
+            if self.execution_hint == NoteExecutionHint::None {
+                bail!("Notes with a network execution tag require an execution hint");
+            }
+        }
+
+        Ok(())
+    }
+}
+
 /// Creates a note from a compiled package
 ///
+/// Both public and private notes are supported, depending on `config.note_type`.
+///
 /// # Arguments
 /// * `client` - The Miden client instance
 /// * `package` - The compiled package containing the note script
@@ -273,50 +1357,68 @@ impl Default for NoteCreationConfig {
 /// The created `Note`
 ///
 /// # Errors
-/// Returns an error if note creation fails
+/// Returns an error if the package isn't a program, the config is invalid or note creation fails
 pub fn create_note_from_package(
     client: &mut Client<FilesystemKeyStore<StdRng>>,
     package: Arc<Package>,
     sender_id: AccountId,
     config: NoteCreationConfig,
 ) -> Result<Note> {
-    let note_program = package.unwrap_program();
-    let note_script = NoteScript::from_parts(
-        note_program.mast_forest().clone(),
-        note_program.entrypoint(),
-    );
-
+    let note_script = note_script_from_package(&package)?;
     let serial_num = client.rng().draw_word();
-    let note_inputs = NoteInputs::new(config.inputs).context("Failed to create note inputs")?;
-    let recipient = NoteRecipient::new(serial_num, note_script, note_inputs);
 
-    let metadata = NoteMetadata::new(
-        sender_id,
-        config.note_type,
-        config.tag,
-        config.execution_hint,
-        config.aux,
-    )
-    .context("Failed to create note metadata")?;
-
-    Ok(Note::new(config.assets, metadata, recipient))
+    note_from_script(note_script, serial_num, sender_id, config)
 }
 
-pub fn create_testing_note_from_package(
+/// Creates a note from a compiled package with a fixed serial number
+///
+/// Unlike `create_note_from_package`, the serial number isn't drawn from the client RNG, so the
+/// note ID is reproducible and golden tests can assert it. Two notes with the same script, inputs
+/// and serial number share a nullifier, so only one of them can ever be consumed; keeping serial
+/// numbers unique across notes is the caller's responsibility.
+///
+/// # Arguments
+/// * `serial_num` - The serial number of the note
+/// * `package` - The compiled package containing the note script
+/// * `sender_id` - The ID of the account sending the note
+/// * `config` - Configuration for note creation
+///
+/// # Returns
+/// The created `Note`
+///
+/// # Errors
+/// Returns an error if the package isn't a program, the config is invalid or note creation fails
+pub fn create_note_deterministic(
+    serial_num: Word,
     package: Arc<Package>,
     sender_id: AccountId,
     config: NoteCreationConfig,
 ) -> Result<Note> {
+    let note_script = note_script_from_package(&package)?;
+
+    note_from_script(note_script, serial_num, sender_id, config)
+}
+
+/// Returns the note script of a compiled program package
+fn note_script_from_package(package: &Package) -> Result<NoteScript> {
+    ensure_package_kind(package, PackageKind::Program)?;
     let note_program = package.unwrap_program();
-    let note_script = NoteScript::from_parts(
+
+    Ok(NoteScript::from_parts(
         note_program.mast_forest().clone(),
         note_program.entrypoint(),
-    );
+    ))
+}
 
-    // get 4 random u64s and convert them to a word
-    let random_u64s = [0_u64; 4];
-    let serial_num =
-        Word::try_from(random_u64s).context("Failed to convert random u64s to word")?;
+/// Creates a note running `note_script` with the given serial number
+fn note_from_script(
+    note_script: NoteScript,
+    serial_num: Word,
+    sender_id: AccountId,
+    config: NoteCreationConfig,
+) -> Result<Note> {
+    config.validate()?;
+    validate_note_inputs(&config.inputs)?;
 
     let note_inputs = NoteInputs::new(config.inputs).context("Failed to create note inputs")?;
     let recipient = NoteRecipient::new(serial_num, note_script, note_inputs);
@@ -333,6 +1435,21 @@ pub fn create_testing_note_from_package(
     Ok(Note::new(config.assets, metadata, recipient))
 }
 
+pub fn create_testing_note_from_package(
+    package: Arc<Package>,
+    sender_id: AccountId,
+    config: NoteCreationConfig,
+) -> Result<Note> {
+    let note_script = note_script_from_package(&package)?;
+
+    // get 4 random u64s and convert them to a word
+    let random_u64s = [0_u64; 4];
+    let serial_num =
+        Word::try_from(random_u64s).context("Failed to convert random u64s to word")?;
+
+    note_from_script(note_script, serial_num, sender_id, config)
+}
+
 /// Creates a basic wallet account with authentication
 ///
 /// # Arguments
@@ -374,5 +1491,5047 @@ pub async fn create_basic_wallet_account(
         .add_key(&AuthSecretKey::RpoFalcon512(key_pair))
         .context("Failed to add key to keystore")?;
 
+    verify_signing_capability(&keystore, &account, RPO_FALCON_512_PUBLIC_KEY_SLOT)?;
+
+    Ok(account)
+}
+
+/// Creates a fungible faucet account with authentication
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `keystore` - The keystore for storing authentication keys
+/// * `symbol` - The token symbol of the faucet (e.g. "TST")
+/// * `decimals` - The number of decimals of the token
+/// * `max_supply` - The maximum amount of tokens the faucet can issue
+/// * `config` - Configuration for account creation, `account_type` must be `FungibleFaucet`
+///
+/// # Returns
+/// The created faucet `Account`
+///
+/// # Errors
+/// Returns an error if the config isn't a fungible faucet config, the symbol or other faucet
+/// parameters are invalid, or account creation, key generation, or keystore operations fail
+pub async fn create_fungible_faucet_account(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    keystore: Arc<FilesystemKeyStore<StdRng>>,
+    symbol: &str,
+    decimals: u8,
+    max_supply: u64,
+    config: AccountCreationConfig,
+) -> Result<Account> {
+    if config.account_type != AccountType::FungibleFaucet {
+        bail!(
+            "Faucet accounts must use AccountType::FungibleFaucet, got {:?}",
+            config.account_type
+        );
+    }
+
+    let token_symbol = token_symbol(symbol)?;
+    let faucet_component = BasicFungibleFaucet::new(token_symbol, decimals, Felt::new(max_supply))
+        .context("Failed to create fungible faucet component")?;
+
+    let mut init_seed = [0_u8; 32];
+    client.rng().fill_bytes(&mut init_seed);
+
+    let key_pair = SecretKey::with_rng(client.rng());
+
+    let (account, seed) = AccountBuilder::new(init_seed)
+        .account_type(config.account_type)
+        .storage_mode(config.storage_mode)
+        .with_auth_component(AuthRpoFalcon512::new(key_pair.public_key()))
+        .with_component(faucet_component)
+        .build()
+        .context("Failed to build fungible faucet account")?;
+
+    client
+        .add_account(&account, Some(seed), false)
+        .await
+        .context("Failed to add faucet account to client")?;
+
+    keystore
+        .add_key(&AuthSecretKey::RpoFalcon512(key_pair))
+        .context("Failed to add key to keystore")?;
+
     Ok(account)
 }
+
+/// Returns the full notes created by a transaction
+///
+/// Notes that are only partially known (e.g. private output notes of other accounts) are skipped.
+pub fn created_full_notes(tx_result: &TransactionResult) -> Vec<Note> {
+    tx_result
+        .created_notes()
+        .iter()
+        .filter_map(|output_note| match output_note {
+            OutputNote::Full(note) => Some(note.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Mints fungible assets from a faucet into a P2ID note targeting an account
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `faucet_id` - The ID of the faucet minting the asset
+/// * `target_id` - The ID of the account the minted note is addressed to
+/// * `amount` - The amount of tokens to mint
+/// * `note_type` - Whether the minted note is public or private
+///
+/// # Returns
+/// The ID of the submitted mint transaction and the created P2ID note
+///
+/// # Errors
+/// Returns an error if the asset is invalid or the transaction fails to execute or submit
+pub async fn mint_fungible_asset(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    faucet_id: AccountId,
+    target_id: AccountId,
+    amount: u64,
+    note_type: NoteType,
+) -> Result<(TransactionId, Note)> {
+    let asset = FungibleAsset::new(faucet_id, amount).context("Failed to create fungible asset")?;
+
+    let mint_request = TransactionRequestBuilder::new()
+        .build_mint_fungible_asset(asset, target_id, note_type, client.rng())
+        .context("Failed to build mint transaction request")?;
+
+    let tx_result = client
+        .new_transaction(faucet_id, mint_request)
+        .await
+        .context("Failed to create mint transaction")?;
+
+    let tx_id = tx_result.executed_transaction().id();
+    let note = created_full_notes(&tx_result)
+        .into_iter()
+        .next()
+        .context("Mint transaction did not create a note")?;
+
+    client
+        .submit_transaction(tx_result)
+        .await
+        .context("Failed to submit mint transaction")?;
+
+    Ok((tx_id, note))
+}
+
+/// Consumes notes held in memory with the given account
+///
+/// The notes are passed as unauthenticated input notes, so they don't need to be tracked by the
+/// client store.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `account_id` - The ID of the account consuming the notes
+/// * `notes` - The notes to consume
+///
+/// # Returns
+/// The ID of the submitted consume transaction
+///
+/// # Errors
+/// Returns an error if the transaction fails to execute or submit
+pub async fn consume_notes(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    account_id: AccountId,
+    notes: &[Note],
+) -> Result<TransactionId> {
+    let consume_request = TransactionRequestBuilder::new()
+        .unauthenticated_input_notes(notes.iter().cloned().map(|note| (note, None)))
+        .build()
+        .context("Failed to build consume note transaction request")?;
+
+    let tx_result = client
+        .new_transaction(account_id, consume_request)
+        .await
+        .context("Failed to create consume note transaction")?;
+
+    let tx_id = tx_result.executed_transaction().id();
+
+    client
+        .submit_transaction(tx_result)
+        .await
+        .context("Failed to submit consume note transaction")?;
+
+    Ok(tx_id)
+}
+
+/// Interval between two syncs while waiting for the chain to advance
+const SYNC_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Syncs the client until a transaction is committed
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `tx_id` - The ID of the transaction to wait for
+/// * `max_blocks` - The number of blocks to wait before giving up
+///
+/// # Errors
+/// Returns an error if syncing fails, the transaction is discarded, or it isn't committed within
+/// `max_blocks` blocks
+pub async fn wait_for_transaction(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    tx_id: TransactionId,
+    max_blocks: u32,
+) -> Result<()> {
+    let start_block = client
+        .sync_state()
+        .await
+        .context("Failed to sync state")?
+        .block_num
+        .as_u32();
+
+    loop {
+        let records = client
+            .get_transactions(TransactionFilter::Ids(vec![tx_id]))
+            .await
+            .context("Failed to get transaction records")?;
+
+        match records.first().map(|record| &record.status) {
+            Some(TransactionStatus::Committed { .. }) => return Ok(()),
+            Some(TransactionStatus::Discarded(cause)) => {
+                bail!("Transaction {} was discarded: {:?}", tx_id.to_hex(), cause)
+            }
+            _ => {}
+        }
+
+        tokio::time::sleep(SYNC_POLL_INTERVAL).await;
+
+        let block_num = client
+            .sync_state()
+            .await
+            .context("Failed to sync state")?
+            .block_num
+            .as_u32();
+
+        if block_num.saturating_sub(start_block) > max_blocks {
+            bail!(
+                "Transaction {} was not committed within {} blocks",
+                tx_id.to_hex(),
+                max_blocks
+            );
+        }
+    }
+}
+
+/// Number of blocks helpers wait for their own transactions to be committed
+const DEFAULT_MAX_BLOCKS: u32 = 20;
+
+/// Amount of tokens minted into each fixture wallet
+pub const FIXTURE_MINT_AMOUNT: u64 = 1_000;
+
+/// Ready-to-use test environment with a client, a fungible faucet and funded wallets
+pub struct TestFixture {
+    client: Client<FilesystemKeyStore<StdRng>>,
+    keystore: Arc<FilesystemKeyStore<StdRng>>,
+    faucet: Account,
+    wallets: Vec<Account>,
+}
+
+impl TestFixture {
+    /// Creates a fixture with one faucet and one wallet funded with `FIXTURE_MINT_AMOUNT` tokens
+    ///
+    /// # Errors
+    /// Returns an error if client setup, account creation, minting or consuming fails
+    pub async fn new() -> Result<Self> {
+        Self::with_wallets(1).await
+    }
+
+    /// Creates a fixture with one faucet and `n` wallets, each funded with
+    /// `FIXTURE_MINT_AMOUNT` tokens
+    ///
+    /// # Errors
+    /// Returns an error if client setup, account creation, minting or consuming fails
+    pub async fn with_wallets(n: usize) -> Result<Self> {
+        let ClientSetup {
+            mut client,
+            keystore,
+            ..
+        } = setup_client().await?;
+
+        client.sync_state().await.context("Failed to sync state")?;
+
+        let faucet = create_fixture_faucet(&mut client, keystore.clone()).await?;
+
+        let mut wallets = Vec::with_capacity(n);
+        for _ in 0..n {
+            let wallet = create_funded_wallet(
+                &mut client,
+                keystore.clone(),
+                faucet.id(),
+                FIXTURE_MINT_AMOUNT,
+            )
+            .await?;
+            wallets.push(wallet);
+        }
+
+        Ok(Self {
+            client,
+            keystore,
+            faucet,
+            wallets,
+        })
+    }
+
+    /// Returns the ID of the fixture faucet
+    pub fn faucet_id(&self) -> AccountId {
+        self.faucet.id()
+    }
+
+    /// Returns the ID of the first fixture wallet
+    ///
+    /// # Panics
+    /// Panics if the fixture was created without wallets
+    pub fn wallet_id(&self) -> AccountId {
+        self.wallets[0].id()
+    }
+
+    /// Returns the IDs of all fixture wallets
+    pub fn wallet_ids(&self) -> Vec<AccountId> {
+        self.wallets.iter().map(Account::id).collect()
+    }
+
+    /// Returns the fixture client
+    pub fn client_mut(&mut self) -> &mut Client<FilesystemKeyStore<StdRng>> {
+        &mut self.client
+    }
+
+    /// Returns the fixture keystore
+    pub fn keystore(&self) -> Arc<FilesystemKeyStore<StdRng>> {
+        self.keystore.clone()
+    }
+}
+
+/// Creates the fungible faucet used by `TestFixture` and `quick_start`
+async fn create_fixture_faucet(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    keystore: Arc<FilesystemKeyStore<StdRng>>,
+) -> Result<Account> {
+    create_fungible_faucet_account(
+        client,
+        keystore,
+        "TST",
+        8,
+        1_000_000_000,
+        AccountCreationConfig::faucet(),
+    )
+    .await
+    .context("Failed to create fixture faucet")
+}
+
+/// Creates a basic wallet and funds it with `amount` tokens minted by `faucet_id`, waiting for
+/// both the mint and the consume transaction to be committed
+async fn create_funded_wallet(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    keystore: Arc<FilesystemKeyStore<StdRng>>,
+    faucet_id: AccountId,
+    amount: u64,
+) -> Result<Account> {
+    let wallet = create_basic_wallet_account(client, keystore, AccountCreationConfig::default())
+        .await
+        .context("Failed to create fixture wallet")?;
+
+    let (mint_tx_id, note) =
+        mint_fungible_asset(client, faucet_id, wallet.id(), amount, NoteType::Public)
+            .await
+            .context("Failed to mint into fixture wallet")?;
+    wait_for_transaction(client, mint_tx_id, DEFAULT_MAX_BLOCKS).await?;
+
+    let consume_tx_id = consume_notes(client, wallet.id(), &[note])
+        .await
+        .context("Failed to consume minted note in fixture wallet")?;
+    wait_for_transaction(client, consume_tx_id, DEFAULT_MAX_BLOCKS).await?;
+
+    Ok(wallet)
+}
+
+/// Syncs the client repeatedly until it has caught up with the chain tip
+///
+/// The client is considered caught up once a sync no longer advances its block number.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `on_block` - Callback invoked with the block number reached by each sync
+///
+/// # Returns
+/// The `SyncSummary` combining every sync performed
+///
+/// # Errors
+/// Returns an error if any sync fails
+pub async fn sync_with_progress(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    mut on_block: impl FnMut(u32),
+) -> Result<SyncSummary> {
+    let mut summary = client.sync_state().await.context("Failed to sync state")?;
+    let mut last_block = summary.block_num.as_u32();
+    on_block(last_block);
+
+    loop {
+        let next = client.sync_state().await.context("Failed to sync state")?;
+        let block_num = next.block_num.as_u32();
+        summary.combine_with(next);
+
+        if block_num <= last_block {
+            return Ok(summary);
+        }
+
+        on_block(block_num);
+        last_block = block_num;
+    }
+}
+
+/// Storage slot reserved for the faucet data of fungible faucet accounts
+const FAUCET_DATA_SLOT: u8 = 0;
+
+/// Returns the amount of tokens issued so far by a fungible faucet account
+fn faucet_issuance(faucet: &Account) -> Result<u64> {
+    let faucet_data = faucet
+        .storage()
+        .get_item(FAUCET_DATA_SLOT)
+        .context("Failed to read faucet data slot")?;
+
+    // The total issuance is stored in the last element of the faucet data word
+    Ok(faucet_data[3].as_int())
+}
+
+/// Mints fungible assets from a faucet into one P2ID note per target in a single transaction
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `faucet_id` - The ID of the faucet minting the assets
+/// * `targets` - The accounts to fund and the amount of tokens each one receives
+/// * `note_type` - Whether the minted notes are public or private
+///
+/// # Returns
+/// The ID of the submitted mint transaction and the created notes, in the order of `targets`
+///
+/// # Errors
+/// Returns an error if the faucet is unknown to the client, the total amount exceeds the
+/// faucet's remaining supply, or the transaction fails to execute or submit
+pub async fn mint_to_many(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    faucet_id: AccountId,
+    targets: &[(AccountId, u64)],
+    note_type: NoteType,
+) -> Result<(TransactionId, Vec<Note>)> {
+    let faucet_record = client
+        .get_account(faucet_id)
+        .await
+        .context("Failed to get faucet account")?
+        .context("Faucet account not found in client store")?;
+    let faucet = faucet_record.account();
+
+    let max_supply = BasicFungibleFaucet::try_from(faucet)
+        .context("Account is not a basic fungible faucet")?
+        .max_supply()
+        .as_int();
+    let issued = faucet_issuance(faucet)?;
+
+    let total = targets
+        .iter()
+        .try_fold(0_u64, |total, (_, amount)| total.checked_add(*amount))
+        .context("Total mint amount overflows u64")?;
+    if issued.saturating_add(total) > max_supply {
+        bail!(
+            "Minting {} tokens would exceed the faucet max supply of {} ({} already issued)",
+            total,
+            max_supply,
+            issued
+        );
+    }
+
+    let mut notes = Vec::with_capacity(targets.len());
+    for (target_id, amount) in targets {
+        let asset =
+            FungibleAsset::new(faucet_id, *amount).context("Failed to create fungible asset")?;
+        let note = create_p2id_note(
+            faucet_id,
+            *target_id,
+            vec![asset.into()],
+            note_type,
+            Felt::ZERO,
+            client.rng(),
+        )
+        .context("Failed to create P2ID note")?;
+        notes.push(note);
+    }
+
+    let mint_request = TransactionRequestBuilder::new()
+        .own_output_notes(notes.iter().cloned().map(OutputNote::Full))
+        .build()
+        .context("Failed to build mint transaction request")?;
+
+    let tx_result = client
+        .new_transaction(faucet_id, mint_request)
+        .await
+        .context("Failed to create mint transaction")?;
+
+    let tx_id = tx_result.executed_transaction().id();
+
+    client
+        .submit_transaction(tx_result)
+        .await
+        .context("Failed to submit mint transaction")?;
+
+    Ok((tx_id, notes))
+}
+
+/// Human-readable summary of a note's contents
+#[derive(Debug, Clone)]
+pub struct NoteDescription {
+    pub id: NoteId,
+    pub script_root: Word,
+    pub serial_num: Word,
+    pub inputs: Vec<Felt>,
+    pub assets: Vec<Asset>,
+    pub tag: NoteTag,
+    pub note_type: NoteType,
+}
+
+impl fmt::Display for NoteDescription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Note {}", self.id.to_hex())?;
+        writeln!(f, "  script root: {}", self.script_root.to_hex())?;
+        writeln!(f, "  serial num:  {}", self.serial_num.to_hex())?;
+        writeln!(f, "  note type:   {:?}", self.note_type)?;
+        writeln!(f, "  tag:         {}", self.tag.as_u32())?;
+
+        writeln!(f, "  inputs ({}):", self.inputs.len())?;
+        for (index, input) in self.inputs.iter().enumerate() {
+            writeln!(f, "    [{index}] {}", input.as_int())?;
+        }
+
+        write!(f, "  assets ({}):", self.assets.len())?;
+        for asset in &self.assets {
+            write!(f, "\n    {asset:?}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Decodes a note into a `NoteDescription` for debugging
+///
+/// Works for any note, including P2ID notes and notes built from custom script packages.
+///
+/// # Arguments
+/// * `note` - The note to describe
+///
+/// # Returns
+/// A `NoteDescription` holding the note's script root, serial number, inputs, assets and metadata
+pub fn describe_note(note: &Note) -> NoteDescription {
+    NoteDescription {
+        id: note.id(),
+        script_root: note.script().root(),
+        serial_num: note.serial_num(),
+        inputs: note.inputs().values().to_vec(),
+        assets: note.assets().iter().cloned().collect(),
+        tag: note.metadata().tag(),
+        note_type: note.metadata().note_type(),
+    }
+}
+
+/// Kind of an account storage slot
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlotKind {
+    Value,
+    Map,
+}
+
+impl fmt::Display for SlotKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SlotKind::Value => write!(f, "value"),
+            SlotKind::Map => write!(f, "map"),
+        }
+    }
+}
+
+/// Checks that the storage slots of a config match the storage layout declared by a component
+///
+/// # Errors
+/// Returns `ConfigError::StorageLayoutMismatch` if the slot count differs or a slot has a
+/// different kind (value or map) than the one declared by the component metadata
+fn validate_storage_layout(
+    metadata: &AccountComponentMetadata,
+    storage_slots: &[StorageSlot],
+) -> Result<()> {
+    let mut declared = Vec::new();
+    for entry in metadata.storage_entries() {
+        let kind = if matches!(entry, StorageEntry::Map { .. }) {
+            SlotKind::Map
+        } else {
+            SlotKind::Value
+        };
+        declared.extend(entry.slot_indices().map(|slot_index| (slot_index, kind)));
+    }
+    declared.sort_by_key(|(slot_index, _)| *slot_index);
+
+    let expected: Vec<_> = declared.into_iter().map(|(_, kind)| kind).collect();
+    let got: Vec<_> = storage_slots
+        .iter()
+        .map(|slot| match slot {
+            StorageSlot::Map(_) => SlotKind::Map,
+            _ => SlotKind::Value,
+        })
+        .collect();
+
+    if expected != got {
+        return Err(ConfigError::StorageLayoutMismatch { expected, got }.into());
+    }
+
+    Ok(())
+}
+
+/// Reads account component metadata from a template file
+///
+/// Files with a `.toml` extension are parsed as TOML, any other file is deserialized from its
+/// binary encoding.
+fn read_component_metadata(template_path: &Path) -> Result<AccountComponentMetadata> {
+    let is_toml = template_path
+        .extension()
+        .is_some_and(|extension| extension == "toml");
+
+    if is_toml {
+        let toml = std::fs::read_to_string(template_path).context(format!(
+            "Failed to read component template from {}",
+            template_path.display()
+        ))?;
+        AccountComponentMetadata::from_toml(&toml)
+            .context("Failed to parse component template TOML")
+    } else {
+        let bytes = std::fs::read(template_path).context(format!(
+            "Failed to read component template from {}",
+            template_path.display()
+        ))?;
+        AccountComponentMetadata::read_from_bytes(&bytes)
+            .context("Failed to deserialize component template")
+    }
+}
+
+/// Creates an account from a component template file paired with a compiled library
+///
+/// This supports templates authored separately from the compiled MASM instead of the metadata
+/// embedded in the package.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `keystore` - The keystore for storing authentication keys
+/// * `template_path` - Path to a `.toml` or binary serialized component template
+/// * `library_package` - The compiled package containing the component library
+/// * `config` - Configuration for account creation
+///
+/// # Returns
+/// The created `Account`
+///
+/// # Errors
+/// Returns an error if the template can't be read, its storage layout doesn't match
+/// `config.storage_slots`, the package isn't a library, or account creation fails
+pub async fn create_account_from_template_file(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    keystore: Arc<FilesystemKeyStore<StdRng>>,
+    template_path: &Path,
+    library_package: Arc<Package>,
+    config: AccountCreationConfig,
+) -> Result<Account> {
+    ensure_package_kind(&library_package, PackageKind::Library)?;
+
+    let metadata = read_component_metadata(template_path)?;
+    validate_storage_layout(&metadata, &config.storage_slots)?;
+
+    let account_component = account_component_from_metadata(metadata, &library_package, &config)
+        .context("Failed to create account component from template")?;
+
+    let mut init_seed = [0_u8; 32];
+    client.rng().fill_bytes(&mut init_seed);
+
+    let key_pair = SecretKey::with_rng(client.rng());
+
+    let (account, seed) = AccountBuilder::new(init_seed)
+        .account_type(config.account_type)
+        .storage_mode(config.storage_mode)
+        .with_auth_component(AuthRpoFalcon512::new(key_pair.public_key()))
+        .with_component(account_component)
+        .build()
+        .context("Failed to build account")?;
+
+    client
+        .add_account(&account, Some(seed), false)
+        .await
+        .context("Failed to add account to client")?;
+
+    keystore
+        .add_key(&AuthSecretKey::RpoFalcon512(key_pair))
+        .context("Failed to add key to keystore")?;
+
+    Ok(account)
+}
+
+/// Copy of the client store file taken by `snapshot_store`
+///
+/// The copy is deleted when the snapshot is dropped.
+pub struct StoreSnapshot {
+    path: PathBuf,
+    params: SetupParams,
+    seed: Option<Word>,
+}
+
+impl StoreSnapshot {
+    /// Returns the path of the snapshot copy
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the parameters of the client whose store was captured
+    pub fn params(&self) -> &SetupParams {
+        &self.params
+    }
+}
+
+impl Drop for StoreSnapshot {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// Returns the path of a file SQLite keeps next to a database file, e.g. its `-wal` log
+fn sqlite_sidecar_path(path: &Path, suffix: &str) -> PathBuf {
+    let mut sidecar_path = path.as_os_str().to_owned();
+    sidecar_path.push(suffix);
+    PathBuf::from(sidecar_path)
+}
+
+/// Moves every write still held in a SQLite database's write-ahead log into the database file
+///
+/// The database must not be open elsewhere, so that the checkpoint isn't blocked by readers.
+fn checkpoint_wal(path: &Path) -> Result<()> {
+    let connection =
+        rusqlite::Connection::open_with_flags(path, rusqlite::OpenFlags::SQLITE_OPEN_READ_WRITE)
+            .context(format!("Failed to open {}", path.display()))?;
+    connection
+        .execute_batch("PRAGMA wal_checkpoint(TRUNCATE);")
+        .context(format!("Failed to checkpoint {}", path.display()))
+}
+
+/// Draws a seed for the rebuilt client if the client RNG is seeded
+fn next_client_seed(setup: &mut ClientSetup) -> Option<Word> {
+    setup
+        .params
+        .seeded_rng
+        .then(|| setup.client.rng().draw_word())
+}
+
+/// Captures the client store so it can later be restored with `restore_store`
+///
+/// The client is dropped to close its store connection and the write-ahead log is checkpointed
+/// into the store file before it is copied, so the snapshot holds every committed write. A
+/// fresh client is built with the same parameters afterwards (endpoint, keystore, store and
+/// debug mode). A seeded client RNG is re-seeded from the old one, so runs stay reproducible.
+///
+/// # Arguments
+/// * `setup` - The client setup whose store is captured
+///
+/// # Returns
+/// A new `ClientSetup` on the unchanged store and the `StoreSnapshot`
+///
+/// # Errors
+/// Returns an error if the store can't be checkpointed or copied, or the client can't be rebuilt
+pub async fn snapshot_store(mut setup: ClientSetup) -> Result<(ClientSetup, StoreSnapshot)> {
+    let resume_seed = next_client_seed(&mut setup);
+    let restore_seed = next_client_seed(&mut setup);
+    let params = setup.params.clone();
+    drop(setup);
+
+    let store_path = &params.store_path;
+    let snapshot_path = std::env::temp_dir().join(format!(
+        "miden-store-snapshot-{}.sqlite3",
+        rand::random::<u64>()
+    ));
+
+    checkpoint_wal(store_path)?;
+    std::fs::copy(store_path, &snapshot_path).context(format!(
+        "Failed to copy store {} to {}",
+        store_path.display(),
+        snapshot_path.display()
+    ))?;
+
+    let setup = setup_client_from_params(&params, resume_seed).await?;
+    let snapshot = StoreSnapshot {
+        path: snapshot_path,
+        params,
+        seed: restore_seed,
+    };
+    Ok((setup, snapshot))
+}
+
+/// Restores the client store to a snapshot taken with `snapshot_store`
+///
+/// The client is dropped to close its store connection before the store file is replaced, and a
+/// fresh client is built on the restored store with the parameters of the captured client.
+///
+/// # Arguments
+/// * `setup` - The client setup whose store is replaced
+/// * `snapshot` - The snapshot to restore
+///
+/// # Returns
+/// A new `ClientSetup` on the restored store
+///
+/// # Errors
+/// Returns an error if the snapshot was taken from another store, the store file can't be
+/// replaced or the client can't be rebuilt
+pub async fn restore_store(setup: ClientSetup, snapshot: &StoreSnapshot) -> Result<ClientSetup> {
+    if setup.params.store_path != snapshot.params.store_path {
+        bail!(
+            "Snapshot of store {} can't be restored into store {}",
+            snapshot.params.store_path.display(),
+            setup.params.store_path.display()
+        );
+    }
+    drop(setup);
+
+    let store_path = &snapshot.params.store_path;
+
+    // A leftover write-ahead log would be replayed on top of the restored database, and its
+    // shared-memory index would describe the old log
+    for suffix in ["-wal", "-shm"] {
+        let sidecar_path = sqlite_sidecar_path(store_path, suffix);
+        if sidecar_path.exists() {
+            std::fs::remove_file(&sidecar_path).context(format!(
+                "Failed to remove store file {}",
+                sidecar_path.display()
+            ))?;
+        }
+    }
+
+    std::fs::copy(&snapshot.path, store_path).context(format!(
+        "Failed to restore store {} from {}",
+        store_path.display(),
+        snapshot.path.display()
+    ))?;
+
+    setup_client_from_params(&snapshot.params, snapshot.seed).await
+}
+
+/// Returns the MAST root of a program package's entrypoint
+///
+/// # Arguments
+/// * `package` - The compiled program package (e.g. a note script)
+///
+/// # Returns
+/// The MAST root of the program entrypoint, which changes whenever the program's code does
+///
+/// # Errors
+/// Returns `HelperError::UnexpectedPackageKind` if the package isn't a program
+pub fn program_root(package: &Package) -> Result<Word> {
+    ensure_package_kind(package, PackageKind::Program)?;
+    Ok(package.unwrap_program().hash())
+}
+
+/// Returns the digest of a library package's MAST forest
+///
+/// # Arguments
+/// * `package` - The compiled library or account component package
+///
+/// # Returns
+/// The library digest, which commits to all of the library's procedures
+///
+/// # Errors
+/// Returns `HelperError::UnexpectedPackageKind` if the package is a program
+pub fn library_root(package: &Package) -> Result<Word> {
+    ensure_package_kind(package, PackageKind::Library)?;
+    Ok(*package.unwrap_library().digest())
+}
+
+/// Returns the IDs of the notes that are not yet known to the client store
+async fn unknown_note_ids(
+    client: &Client<FilesystemKeyStore<StdRng>>,
+    note_ids: &[NoteId],
+) -> Result<Vec<NoteId>> {
+    let mut unknown = Vec::new();
+    for note_id in note_ids {
+        let record = client
+            .get_input_note(*note_id)
+            .await
+            .context("Failed to get input note")?;
+        if record.is_none() {
+            unknown.push(*note_id);
+        }
+    }
+    Ok(unknown)
+}
+
+/// Consumes notes known to the client store by their IDs
+///
+/// Unlike `consume_notes`, the notes are consumed as authenticated input notes, which is how a
+/// client consumes notes it discovered on the network.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `account_id` - The ID of the account consuming the notes
+/// * `note_ids` - The IDs of the notes to consume
+///
+/// # Returns
+/// The ID of the submitted consume transaction
+///
+/// # Errors
+/// Returns an error if any note is still unknown to the client after syncing, or the
+/// transaction fails to execute or submit
+pub async fn consume_notes_by_id(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    account_id: AccountId,
+    note_ids: &[NoteId],
+) -> Result<TransactionId> {
+    if !unknown_note_ids(client, note_ids).await?.is_empty() {
+        client.sync_state().await.context("Failed to sync state")?;
+
+        let unknown = unknown_note_ids(client, note_ids).await?;
+        if !unknown.is_empty() {
+            let unknown: Vec<String> = unknown.iter().map(NoteId::to_hex).collect();
+            bail!("Notes not known to the client: {}", unknown.join(", "));
+        }
+    }
+
+    let consume_request = TransactionRequestBuilder::new()
+        .build_consume_notes(note_ids.to_vec())
+        .context("Failed to build consume note transaction request")?;
+
+    let tx_result = client
+        .new_transaction(account_id, consume_request)
+        .await
+        .context("Failed to create consume note transaction")?;
+
+    let tx_id = tx_result.executed_transaction().id();
+
+    client
+        .submit_transaction(tx_result)
+        .await
+        .context("Failed to submit consume note transaction")?;
+
+    Ok(tx_id)
+}
+
+/// Publishes a private note and returns the note file its recipient needs to consume it
+///
+/// Only the note header is stored on-chain, while the note details are kept in the sender's
+/// client store. The returned bytes must be handed to the recipient, who registers the note with
+/// `import_note_file`.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `sender_id` - The ID of the account publishing the note
+/// * `note` - The private note to publish
+///
+/// # Returns
+/// The ID of the submitted transaction and the serialized `NoteFile`
+///
+/// # Errors
+/// Returns an error if the note isn't private or the transaction fails to execute or submit
+pub async fn publish_private_note(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    sender_id: AccountId,
+    note: Note,
+) -> Result<(TransactionId, Vec<u8>)> {
+    if note.metadata().note_type() != NoteType::Private {
+        bail!(
+            "Expected a private note, got a {:?} note",
+            note.metadata().note_type()
+        );
+    }
+
+    let note_file = export_note(client, &note).await?;
+
+    let publish_request = TransactionRequestBuilder::new()
+        .own_output_notes(vec![OutputNote::Full(note)])
+        .build()
+        .context("Failed to build note publish transaction request")?;
+
+    let tx_result = client
+        .new_transaction(sender_id, publish_request)
+        .await
+        .context("Failed to create note publish transaction")?;
+
+    let tx_id = tx_result.executed_transaction().id();
+
+    client
+        .submit_transaction(tx_result)
+        .await
+        .context("Failed to submit note publish transaction")?;
+
+    Ok((tx_id, note_file))
+}
+
+/// Registers a note file produced by `publish_private_note` with the client
+///
+/// Same as `import_note`, which accepts every note file produced by the helpers.
+///
+/// # Arguments
+/// * `client` - The Miden client instance of the recipient
+/// * `bytes` - The serialized `NoteFile`
+///
+/// # Returns
+/// The ID of the imported note
+///
+/// # Errors
+/// Returns an error if the bytes aren't a valid note file or the import fails
+pub async fn import_note_file(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    bytes: &[u8],
+) -> Result<NoteId> {
+    import_note(client, bytes).await
+}
+
+/// Returns the MAST root of the exported library procedure named exactly `name`
+///
+/// Only the procedure name is compared, not its module path, so the first export with that name
+/// in any module is used.
+///
+/// # Errors
+/// Returns `HelperError::UnexpectedPackageKind` if the package isn't a library, or
+/// `HelperError::MissingProcedure` if it doesn't export a procedure named `name`
+fn exported_procedure_root(package: &Package, name: &str) -> Result<Word> {
+    ensure_package_kind(package, PackageKind::Library)?;
+    let library = package.unwrap_library();
+
+    let export = library
+        .exports()
+        .find(|export| export.name.as_str() == name)
+        .ok_or_else(|| HelperError::MissingProcedure {
+            name: name.to_string(),
+        })?;
+    let node_id = library.get_export_node_id(export);
+
+    Ok(library.mast_forest()[node_id].digest())
+}
+
+/// Increments the counter of a counter contract account through a transaction script
+///
+/// This calls the account's `increment-count` procedure directly instead of consuming an
+/// increment note.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `counter_id` - The ID of the counter account
+/// * `package` - The compiled counter account package
+///
+/// # Returns
+/// The new count word read from the counter's storage map in slot 0
+///
+/// # Errors
+/// Returns an error if the package doesn't export `increment-count`, the script fails to
+/// compile, or the transaction fails to execute or submit
+pub async fn increment_counter_directly(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    counter_id: AccountId,
+    package: Arc<Package>,
+) -> Result<Word> {
+    let increment_root = exported_procedure_root(&package, "increment-count")?;
+
+    // The procedure returns the new count, which the script drops
+    let script_source = format!("begin\n    call.{}\n    drop\nend", increment_root.to_hex());
+    let tx_script = ScriptBuilder::new(true)
+        .compile_tx_script(script_source)
+        .context("Failed to compile increment transaction script")?;
+
+    let increment_request = TransactionRequestBuilder::new()
+        .custom_script(tx_script)
+        .build()
+        .context("Failed to build increment transaction request")?;
+
+    let tx_result = client
+        .new_transaction(counter_id, increment_request)
+        .await
+        .context("Failed to create increment transaction")?;
+
+    client
+        .submit_transaction(tx_result)
+        .await
+        .context("Failed to submit increment transaction")?;
+
+    let counter_record = client
+        .get_account(counter_id)
+        .await
+        .context("Failed to get counter account")?
+        .context("Counter account not found in client store")?;
+
+    counter_record
+        .account()
+        .storage()
+        .get_map_item(0, counter_count_key())
+        .context("Failed to read counter value")
+}
+
+/// Returns the total amount of tokens issued by a fungible faucet
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `faucet_id` - The ID of the faucet account
+///
+/// # Returns
+/// The issuance stored in the faucet account record
+///
+/// # Errors
+/// Returns an error if the faucet is unknown to the client or its storage can't be read
+pub async fn faucet_total_issuance(
+    client: &Client<FilesystemKeyStore<StdRng>>,
+    faucet_id: AccountId,
+) -> Result<u64> {
+    let faucet_record = client
+        .get_account(faucet_id)
+        .await
+        .context("Failed to get faucet account")?
+        .context("Faucet account not found in client store")?;
+
+    faucet_issuance(faucet_record.account())
+}
+
+/// Asserts that a fungible faucet has issued exactly `expected` tokens
+///
+/// # Panics
+/// Panics if the faucet's issuance differs from `expected`
+///
+/// # Errors
+/// Returns an error if the issuance can't be read
+pub async fn assert_issuance_eq(
+    client: &Client<FilesystemKeyStore<StdRng>>,
+    faucet_id: AccountId,
+    expected: u64,
+) -> Result<()> {
+    let issuance = faucet_total_issuance(client, faucet_id).await?;
+    assert_eq!(
+        issuance,
+        expected,
+        "Faucet {} issued {} tokens, expected {}",
+        faucet_id.to_hex(),
+        issuance,
+        expected
+    );
+    Ok(())
+}
+
+/// Note script burning the fungible asset it carries when consumed by a basic fungible faucet
+const BURN_NOTE_SCRIPT: &str = "
+use.miden::note
+use.miden::contracts::faucets::basic_fungible->faucet
+
+begin
+    # load the note's single asset
+    push.0 exec.note::get_assets drop
+    padw movup.4 mem_loadw
+    # => [ASSET]
+
+    call.faucet::burn
+    dropw
+end
+";
+
+/// Returns the balance an account holds of a fungible faucet's token
+async fn fungible_balance(
+    client: &Client<FilesystemKeyStore<StdRng>>,
+    account_id: AccountId,
+    faucet_id: AccountId,
+) -> Result<u64> {
+    let record = client
+        .get_account(account_id)
+        .await
+        .context("Failed to get account")?
+        .context("Account not found in client store")?;
+
+    record
+        .account()
+        .vault()
+        .get_balance(faucet_id)
+        .context("Failed to read fungible balance")
+}
+
+/// Burns fungible assets by sending them from a wallet back to their faucet
+///
+/// The wallet publishes a burn note carrying the assets, which the faucet then consumes,
+/// reducing its issuance by `amount`.
+///
+/// # Arguments
+/// * `client` - The Miden client instance, which must hold the keys of both accounts
+/// * `wallet_id` - The ID of the wallet holding the assets
+/// * `faucet_id` - The ID of the faucet that issued the assets
+/// * `amount` - The amount of tokens to burn
+///
+/// # Returns
+/// The ID of the faucet transaction consuming the burn note
+///
+/// # Errors
+/// Returns `HelperError::InsufficientBalance` if the wallet holds less than `amount`, or an
+/// error if any transaction fails to execute, submit or commit
+pub async fn burn_fungible_asset(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    wallet_id: AccountId,
+    faucet_id: AccountId,
+    amount: u64,
+) -> Result<TransactionId> {
+    let available = fungible_balance(client, wallet_id, faucet_id).await?;
+    if available < amount {
+        return Err(HelperError::InsufficientBalance {
+            account_id: wallet_id,
+            faucet_id,
+            available,
+            required: amount,
+        }
+        .into());
+    }
+
+    let note_script = ScriptBuilder::new(true)
+        .compile_note_script(BURN_NOTE_SCRIPT)
+        .context("Failed to compile burn note script")?;
+
+    let asset = FungibleAsset::new(faucet_id, amount).context("Failed to create fungible asset")?;
+    let assets =
+        NoteAssets::new(vec![asset.into()]).context("Failed to create burn note assets")?;
+
+    let serial_num = client.rng().draw_word();
+    let recipient = NoteRecipient::new(serial_num, note_script, NoteInputs::default());
+    let metadata = NoteMetadata::new(
+        wallet_id,
+        NoteType::Public,
+        NoteTag::from_account_id(faucet_id),
+        NoteExecutionHint::always(),
+        Felt::ZERO,
+    )
+    .context("Failed to create burn note metadata")?;
+    let burn_note = Note::new(assets, metadata, recipient);
+
+    let send_request = TransactionRequestBuilder::new()
+        .own_output_notes(vec![OutputNote::Full(burn_note.clone())])
+        .build()
+        .context("Failed to build burn note transaction request")?;
+
+    let send_tx_result = client
+        .new_transaction(wallet_id, send_request)
+        .await
+        .context("Failed to create burn note transaction")?;
+    let send_tx_id = send_tx_result.executed_transaction().id();
+
+    client
+        .submit_transaction(send_tx_result)
+        .await
+        .context("Failed to submit burn note transaction")?;
+    wait_for_transaction(client, send_tx_id, DEFAULT_MAX_BLOCKS).await?;
+
+    consume_notes(client, faucet_id, &[burn_note])
+        .await
+        .context("Failed to consume burn note at the faucet")
+}
+
+/// Creates a basic wallet account whose ID starts with the requested bits (vanity-style)
+///
+/// Account IDs are derived from the initial seed, so seeds are drawn until the leading
+/// `prefix_len` bits of the ID prefix equal the leading bits of `prefix`.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `keystore` - The keystore for storing authentication keys
+/// * `prefix` - The bits to match, aligned to the most significant bit
+/// * `prefix_len` - The number of leading bits to match (at most 64)
+/// * `max_attempts` - The number of seeds to try before giving up
+/// * `config` - Configuration for account creation
+///
+/// # Returns
+/// The created `Account` and its `AccountIdAddress`
+///
+/// # Errors
+/// Returns an error if no matching account ID was found within `max_attempts` seeds, or account
+/// creation, key generation, or keystore operations fail
+pub async fn create_account_with_address_prefix(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    keystore: Arc<FilesystemKeyStore<StdRng>>,
+    prefix: u64,
+    prefix_len: u8,
+    max_attempts: usize,
+    config: AccountCreationConfig,
+) -> Result<(Account, AccountIdAddress)> {
+    if prefix_len > 64 {
+        bail!("Prefix length must be at most 64 bits, got {}", prefix_len);
+    }
+    let mask = u64::MAX
+        .checked_shl(64 - u32::from(prefix_len))
+        .unwrap_or(0);
+
+    let key_pair = SecretKey::with_rng(client.rng());
+
+    for _ in 0..max_attempts {
+        let mut init_seed = [0_u8; 32];
+        client.rng().fill_bytes(&mut init_seed);
+
+        let (account, seed) = AccountBuilder::new(init_seed)
+            .account_type(config.account_type)
+            .storage_mode(config.storage_mode)
+            .with_auth_component(AuthRpoFalcon512::new(key_pair.public_key()))
+            .with_component(BasicWallet)
+            .build()
+            .context("Failed to build basic wallet account")?;
+
+        if account.id().prefix().as_u64() & mask != prefix & mask {
+            continue;
+        }
+
+        client
+            .add_account(&account, Some(seed), false)
+            .await
+            .context("Failed to add account to client")?;
+
+        keystore
+            .add_key(&AuthSecretKey::RpoFalcon512(key_pair))
+            .context("Failed to add key to keystore")?;
+
+        let address = AccountIdAddress::new(account.id(), AddressInterface::BasicWallet);
+        return Ok((account, address));
+    }
+
+    bail!(
+        "No account ID matching the {}-bit prefix {:#018x} found within {} attempts",
+        prefix_len,
+        prefix & mask,
+        max_attempts
+    )
+}
+
+/// Magic bytes at the start of package files written by `save_package`
+const PACKAGE_FILE_MAGIC: &[u8; 4] = b"MPKG";
+
+/// Version of the package file format written by `save_package`
+const PACKAGE_FILE_VERSION: u16 = 1;
+
+/// Saves a compiled package to disk so it can be reused across runs
+///
+/// The serialized package is preceded by a small header (magic bytes and format version) that
+/// `load_package` checks to detect format drift.
+///
+/// # Arguments
+/// * `package` - The compiled package to save
+/// * `path` - The file to write
+///
+/// # Errors
+/// Returns an error if the file can't be written
+pub fn save_package(package: &Package, path: &Path) -> std::io::Result<()> {
+    let mut bytes = Vec::from(*PACKAGE_FILE_MAGIC);
+    bytes.extend_from_slice(&PACKAGE_FILE_VERSION.to_le_bytes());
+    bytes.extend_from_slice(&package.to_bytes());
+
+    std::fs::write(path, bytes)
+}
+
+/// Loads a package saved with `save_package`
+///
+/// # Arguments
+/// * `path` - The file to read
+///
+/// # Returns
+/// The deserialized `Package`
+///
+/// # Errors
+/// Returns `HelperError::InvalidPackageHeader` or `HelperError::UnsupportedPackageVersion` if
+/// the file header isn't recognized, `HelperError::IncompatiblePackage` if the package was built
+/// against another version of the protocol library, or an error if the file can't be read or
+/// deserialized
+pub fn load_package(path: &Path) -> Result<Package> {
+    let bytes =
+        std::fs::read(path).context(format!("Failed to read package file {}", path.display()))?;
+
+    let header_len = PACKAGE_FILE_MAGIC.len() + 2;
+    if bytes.len() < header_len || !bytes.starts_with(PACKAGE_FILE_MAGIC) {
+        return Err(HelperError::InvalidPackageHeader {
+            path: path.to_path_buf(),
+        }
+        .into());
+    }
+
+    let version = u16::from_le_bytes([bytes[4], bytes[5]]);
+    if version != PACKAGE_FILE_VERSION {
+        return Err(HelperError::UnsupportedPackageVersion {
+            path: path.to_path_buf(),
+            found: version,
+            expected: PACKAGE_FILE_VERSION,
+        }
+        .into());
+    }
+
+    let package = Package::read_from_bytes(&bytes[header_len..])
+        .context("Failed to deserialize package from bytes")?;
+    assert_package_compatible(&package)?;
+
+    Ok(package)
+}
+
+/// Syncs the client until an account's nonce reaches at least `min_nonce`
+///
+/// This is more precise than `wait_for_transaction` when several transactions target the same
+/// account.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `account_id` - The ID of the account to watch
+/// * `min_nonce` - The nonce the account must reach
+/// * `max_blocks` - The number of blocks to wait before giving up
+///
+/// # Errors
+/// Returns an error reporting the last observed nonce if the account doesn't reach `min_nonce`
+/// within `max_blocks` blocks, or if syncing fails
+pub async fn wait_for_nonce(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    account_id: AccountId,
+    min_nonce: Felt,
+    max_blocks: u32,
+) -> Result<()> {
+    let start_block = client
+        .sync_state()
+        .await
+        .context("Failed to sync state")?
+        .block_num
+        .as_u32();
+
+    loop {
+        let nonce = client
+            .get_account(account_id)
+            .await
+            .context("Failed to get account")?
+            .context("Account not found in client store")?
+            .account()
+            .nonce();
+
+        if nonce.as_int() >= min_nonce.as_int() {
+            return Ok(());
+        }
+
+        tokio::time::sleep(SYNC_POLL_INTERVAL).await;
+
+        let block_num = client
+            .sync_state()
+            .await
+            .context("Failed to sync state")?
+            .block_num
+            .as_u32();
+
+        if block_num.saturating_sub(start_block) > max_blocks {
+            bail!(
+                "Account {} did not reach nonce {} within {} blocks, last observed nonce is {}",
+                account_id.to_hex(),
+                min_nonce.as_int(),
+                max_blocks,
+                nonce.as_int()
+            );
+        }
+    }
+}
+
+/// Builder packing typed values into the `Felt` inputs of a note
+///
+/// # Example
+/// ```ignore
+/// let inputs = NoteInputsBuilder::new()
+///     .push_account_id(target_id)
+///     .push_u64(amount)?
+///     .build();
+/// let config = NoteCreationConfig { inputs, ..Default::default() };
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct NoteInputsBuilder {
+    inputs: Vec<Felt>,
+}
+
+impl NoteInputsBuilder {
+    /// Creates an empty builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a single field element
+    pub fn push_felt(mut self, value: Felt) -> Self {
+        self.inputs.push(value);
+        self
+    }
+
+    /// Appends a `u64` as a single field element
+    ///
+    /// # Errors
+    /// Returns an error if `value` is not smaller than the field modulus
+    pub fn push_u64(mut self, value: u64) -> Result<Self> {
+        let felt = Felt::try_from(value)
+            .map_err(|_| anyhow::anyhow!("Value {} does not fit in a field element", value))?;
+        self.inputs.push(felt);
+        Ok(self)
+    }
+
+    /// Appends the four elements of a word, in order
+    pub fn push_word(mut self, word: Word) -> Self {
+        self.inputs.extend(word.iter().copied());
+        self
+    }
+
+    /// Appends an account ID as two elements: `[suffix, prefix]`
+    ///
+    /// This is the encoding used by the P2ID note script for its target account.
+    pub fn push_account_id(mut self, account_id: AccountId) -> Self {
+        self.inputs.extend(account_id_to_note_inputs(account_id));
+        self
+    }
+
+    /// Returns the packed inputs, ready for `NoteCreationConfig::inputs`
+    pub fn build(self) -> Vec<Felt> {
+        self.inputs
+    }
+}
+
+/// Creates `count` basic wallet accounts, generating their keys on parallel worker threads
+///
+/// Each worker derives its own `StdRng` from a seed drawn from the client RNG, so key
+/// generation and seed grinding don't contend on `client.rng()`. The accounts are then added
+/// to the client store sequentially.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `keystore` - The keystore for storing authentication keys
+/// * `count` - The number of wallets to create
+/// * `config` - Configuration for account creation
+///
+/// # Returns
+/// The created accounts, where the account at index `i` was built by worker `i`
+///
+/// # Errors
+/// Returns an error if account creation, key generation, or keystore operations fail
+pub async fn create_wallets_parallel(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    keystore: Arc<FilesystemKeyStore<StdRng>>,
+    count: usize,
+    config: AccountCreationConfig,
+) -> Result<Vec<Account>> {
+    let worker_seeds: Vec<[u8; 32]> = (0..count)
+        .map(|_| {
+            let mut seed = [0_u8; 32];
+            client.rng().fill_bytes(&mut seed);
+            seed
+        })
+        .collect();
+
+    let built = std::thread::scope(|scope| {
+        let workers: Vec<_> = worker_seeds
+            .into_iter()
+            .map(|worker_seed| {
+                let config = &config;
+                scope.spawn(move || -> Result<_> {
+                    let mut rng = StdRng::from_seed(worker_seed);
+
+                    let mut init_seed = [0_u8; 32];
+                    rng.fill_bytes(&mut init_seed);
+                    let key_pair = SecretKey::with_rng(&mut rng);
+
+                    let (account, seed) = AccountBuilder::new(init_seed)
+                        .account_type(config.account_type)
+                        .storage_mode(config.storage_mode)
+                        .with_auth_component(AuthRpoFalcon512::new(key_pair.public_key()))
+                        .with_component(BasicWallet)
+                        .build()
+                        .context("Failed to build basic wallet account")?;
+
+                    Ok((account, seed, key_pair))
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .map(|worker| {
+                worker
+                    .join()
+                    .map_err(|_| anyhow::anyhow!("Wallet creation worker panicked"))?
+            })
+            .collect::<Result<Vec<_>>>()
+    })?;
+
+    let mut accounts = Vec::with_capacity(count);
+    for (account, seed, key_pair) in built {
+        client
+            .add_account(&account, Some(seed), false)
+            .await
+            .context("Failed to add account to client")?;
+
+        keystore
+            .add_key(&AuthSecretKey::RpoFalcon512(key_pair))
+            .context("Failed to add key to keystore")?;
+
+        accounts.push(account);
+    }
+
+    Ok(accounts)
+}
+
+/// Returns the IDs of the notes the client knows that an account could still consume
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `account_id` - The ID of the account
+///
+/// # Errors
+/// Returns an error if the client store can't be queried
+pub async fn pending_notes(
+    client: &Client<FilesystemKeyStore<StdRng>>,
+    account_id: AccountId,
+) -> Result<Vec<NoteId>> {
+    let consumable = client
+        .get_consumable_notes(Some(account_id))
+        .await
+        .context("Failed to get consumable notes")?;
+
+    Ok(consumable
+        .into_iter()
+        .map(|(record, _)| record.id())
+        .collect())
+}
+
+/// Asserts that no notes are left for an account to consume
+///
+/// Use this at the end of a test to catch notes that were created but never consumed.
+///
+/// # Panics
+/// Panics listing the pending note IDs if any remain
+///
+/// # Errors
+/// Returns an error if the client store can't be queried
+pub async fn assert_no_pending_notes(
+    client: &Client<FilesystemKeyStore<StdRng>>,
+    account_id: AccountId,
+) -> Result<()> {
+    let pending = pending_notes(client, account_id).await?;
+    let pending: Vec<String> = pending.iter().map(NoteId::to_hex).collect();
+
+    assert!(
+        pending.is_empty(),
+        "Account {} has unconsumed notes: {}",
+        account_id.to_hex(),
+        pending.join(", ")
+    );
+    Ok(())
+}
+
+/// Reads a value storage slot of an account from the client store
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `account_id` - The ID of the account
+/// * `slot` - The index of the value slot
+///
+/// # Returns
+/// The word stored in the slot
+///
+/// # Errors
+/// Returns an error if the account is unknown to the client or the slot can't be read
+pub async fn read_value_slot(
+    client: &Client<FilesystemKeyStore<StdRng>>,
+    account_id: AccountId,
+    slot: u8,
+) -> Result<Word> {
+    let record = client
+        .get_account(account_id)
+        .await
+        .context("Failed to get account")?
+        .context("Account not found in client store")?;
+
+    record
+        .account()
+        .storage()
+        .get_item(slot)
+        .context(format!("Failed to read storage slot {slot}"))
+}
+
+/// Name of the procedure `set_value_slot` calls on the account
+pub const SET_VALUE_SLOT_PROCEDURE: &str = "set-value-slot";
+
+/// Writes a value storage slot of an account through its `set-value-slot` procedure
+///
+/// The account's component package must export a `set-value-slot` procedure which expects
+/// `[slot, VALUE]` on the stack and writes `VALUE` to value slot `slot`. None of the contracts
+/// in `contracts/` export it, so the package is checked before any transaction is built.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `account_id` - The ID of the account
+/// * `slot` - The index of the value slot
+/// * `value` - The word to write
+/// * `package` - The compiled account component package exporting the setter
+///
+/// # Errors
+/// Returns `HelperError::MissingProcedure` if the package doesn't export `set-value-slot`, or an
+/// error if the transaction fails to execute or submit, or the slot doesn't hold `value`
+/// afterwards
+pub async fn set_value_slot(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    account_id: AccountId,
+    slot: u8,
+    value: Word,
+    package: Arc<Package>,
+) -> Result<()> {
+    let setter_root = exported_procedure_root(&package, SET_VALUE_SLOT_PROCEDURE)?;
+
+    let value_elements: Vec<String> = value
+        .iter()
+        .rev()
+        .map(|element| element.as_int().to_string())
+        .collect();
+    let script_source = format!(
+        "begin\n    push.{}\n    push.{}\n    call.{}\n    dropw drop\nend",
+        value_elements.join("."),
+        slot,
+        setter_root.to_hex()
+    );
+    let tx_script = ScriptBuilder::new(true)
+        .compile_tx_script(script_source)
+        .context("Failed to compile setter transaction script")?;
+
+    let set_request = TransactionRequestBuilder::new()
+        .custom_script(tx_script)
+        .build()
+        .context("Failed to build setter transaction request")?;
+
+    let tx_result = client
+        .new_transaction(account_id, set_request)
+        .await
+        .context("Failed to create setter transaction")?;
+
+    client
+        .submit_transaction(tx_result)
+        .await
+        .context("Failed to submit setter transaction")?;
+
+    let stored = read_value_slot(client, account_id, slot).await?;
+    if stored != value {
+        bail!(
+            "Storage slot {} holds {} after the setter transaction, expected {}",
+            slot,
+            stored.to_hex(),
+            value.to_hex()
+        );
+    }
+
+    Ok(())
+}
+
+/// Maximum number of characters in a token symbol
+const MAX_TOKEN_SYMBOL_LEN: usize = 6;
+
+/// Validates and constructs a token symbol from a user-provided string
+///
+/// # Arguments
+/// * `symbol` - The symbol, made of 1 to 6 uppercase ASCII letters (e.g. "TST")
+///
+/// # Errors
+/// Returns `HelperError::InvalidTokenSymbol` describing why the symbol was rejected
+pub fn token_symbol(symbol: &str) -> Result<TokenSymbol> {
+    let invalid = |reason: String| HelperError::InvalidTokenSymbol {
+        symbol: symbol.to_string(),
+        reason,
+    };
+
+    let len = symbol.chars().count();
+    if len == 0 || len > MAX_TOKEN_SYMBOL_LEN {
+        return Err(invalid(format!(
+            "must be between 1 and {MAX_TOKEN_SYMBOL_LEN} characters long, got {len}"
+        ))
+        .into());
+    }
+
+    if let Some(character) = symbol.chars().find(|c| !c.is_ascii_uppercase()) {
+        return Err(invalid(format!(
+            "must only contain uppercase letters A-Z, found `{character}`"
+        ))
+        .into());
+    }
+
+    TokenSymbol::new(symbol).map_err(|err| invalid(err.to_string()).into())
+}
+
+/// Default path of the counter account contract, relative to the integration crate
+pub const COUNTER_CONTRACT_PATH: &str = "../contracts/counter-account";
+
+/// Returns the key under which the counter contract stores its count in the slot 0 map
+pub fn counter_count_key() -> Word {
+    word_from_u64(1)
+}
+
+/// Builds the counter contract and creates a counter account holding `initial` as its count
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `initial` - The initial count
+/// * `contract_path` - Path of the counter contract, defaults to `COUNTER_CONTRACT_PATH`
+///
+/// # Returns
+/// The created counter `Account` and the compiled counter package, for creating notes that call
+/// into it
+///
+/// # Errors
+/// Returns an error if the contract fails to build or account creation fails
+pub async fn create_counter_account(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    initial: u64,
+    contract_path: Option<&Path>,
+) -> Result<(Account, Arc<Package>)> {
+    let contract_path = contract_path.unwrap_or(Path::new(COUNTER_CONTRACT_PATH));
+    let counter_package = Arc::new(
+        build_project_in_dir_async(contract_path, true)
+            .await
+            .context("Failed to build counter account contract")?,
+    );
+
+    let initial_count = word_from_u64(initial);
+    let counter_cfg = AccountCreationConfig {
+        storage_slots: vec![StorageSlot::Map(
+            StorageMap::with_entries([(counter_count_key(), initial_count)])
+                .context("Failed to create storage map with initial counter value")?,
+        )],
+        ..Default::default()
+    };
+
+    let counter_account = create_account_from_package(client, counter_package.clone(), counter_cfg)
+        .await
+        .context("Failed to create counter account")?;
+
+    Ok((counter_account, counter_package))
+}
+
+/// Name under which the entrypoint of a program package is reported in a `PackageDiff`
+const PROGRAM_ENTRYPOINT_NAME: &str = "<entrypoint>";
+
+/// Returns the MAST roots of a package's procedures, keyed by their fully qualified names
+///
+/// Programs are reported as a single entry named `PROGRAM_ENTRYPOINT_NAME`.
+fn procedure_roots(package: &Package) -> BTreeMap<String, Word> {
+    if package.is_program() {
+        return BTreeMap::from([(
+            PROGRAM_ENTRYPOINT_NAME.to_string(),
+            package.unwrap_program().hash(),
+        )]);
+    }
+
+    let library = package.unwrap_library();
+    library
+        .exports()
+        .map(|export| {
+            let node_id = library.get_export_node_id(export);
+            (export.to_string(), library.mast_forest()[node_id].digest())
+        })
+        .collect()
+}
+
+/// Functional differences between two compiled packages
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PackageDiff {
+    /// The package kinds, if they differ
+    pub kind_mismatch: Option<(PackageKind, PackageKind)>,
+    /// Procedures only exported by the first package
+    pub only_in_a: Vec<String>,
+    /// Procedures only exported by the second package
+    pub only_in_b: Vec<String>,
+    /// Procedures exported by both packages whose MAST roots differ
+    pub changed: Vec<String>,
+}
+
+impl PackageDiff {
+    /// Returns `true` if the packages are functionally equivalent
+    pub fn is_empty(&self) -> bool {
+        self.kind_mismatch.is_none()
+            && self.only_in_a.is_empty()
+            && self.only_in_b.is_empty()
+            && self.changed.is_empty()
+    }
+}
+
+/// Compares the procedures of two packages by MAST root
+///
+/// Metadata such as names, versions or descriptions is ignored.
+///
+/// # Arguments
+/// * `a` - The first package
+/// * `b` - The second package
+///
+/// # Returns
+/// A `PackageDiff` listing the procedures that were added, removed or changed
+pub fn package_diff(a: &Package, b: &Package) -> PackageDiff {
+    let (kind_a, kind_b) = (package_kind(a), package_kind(b));
+    let kind_mismatch = (kind_a != kind_b).then_some((kind_a, kind_b));
+
+    let roots_a = procedure_roots(a);
+    let roots_b = procedure_roots(b);
+
+    let mut diff = PackageDiff {
+        kind_mismatch,
+        ..Default::default()
+    };
+    for (name, root) in &roots_a {
+        match roots_b.get(name) {
+            None => diff.only_in_a.push(name.clone()),
+            Some(other_root) if other_root != root => diff.changed.push(name.clone()),
+            Some(_) => {}
+        }
+    }
+    diff.only_in_b = roots_b
+        .keys()
+        .filter(|name| !roots_a.contains_key(*name))
+        .cloned()
+        .collect();
+
+    diff
+}
+
+/// Returns `true` if two packages contain the same code
+///
+/// Programs are compared by their entrypoint root and libraries by the roots of their exported
+/// procedures, ignoring incidental metadata differences.
+pub fn packages_equivalent(a: &Package, b: &Package) -> bool {
+    package_diff(a, b).is_empty()
+}
+
+/// Index of the recall (reclaim) block height in the inputs of P2IDR/P2IDE notes
+const RECALL_HEIGHT_INPUT_INDEX: usize = 2;
+
+/// Returns how many blocks remain until the sender can recall a P2IDR note
+///
+/// The recall height is read from the note inputs, laid out as
+/// `[target_suffix, target_prefix, recall_height, ..]`.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `note` - The P2IDR note
+///
+/// # Returns
+/// The recall height minus the client's sync height, so a value of zero or less means the note
+/// is recallable
+///
+/// # Errors
+/// Returns an error if the note inputs don't contain a recall height or the sync height can't be
+/// read
+pub async fn blocks_until_recallable(
+    client: &Client<FilesystemKeyStore<StdRng>>,
+    note: &Note,
+) -> Result<i64> {
+    let recall_height = note
+        .inputs()
+        .values()
+        .get(RECALL_HEIGHT_INPUT_INDEX)
+        .context("Note inputs do not contain a recall height")?
+        .as_int();
+
+    let sync_height = client
+        .get_sync_height()
+        .await
+        .context("Failed to get sync height")?
+        .as_u32();
+
+    Ok(recall_height as i64 - i64::from(sync_height))
+}
+
+/// Computes the delta that turns the `before` state of an account into its `after` state
+fn account_delta_between(before: &Account, after: &Account) -> Result<AccountDelta> {
+    let mut storage_delta = AccountStorageDelta::new();
+    for (index, (old_slot, new_slot)) in before
+        .storage()
+        .slots()
+        .iter()
+        .zip(after.storage().slots())
+        .enumerate()
+    {
+        let index = u8::try_from(index).context("Storage slot index exceeds u8")?;
+        match (old_slot, new_slot) {
+            (StorageSlot::Map(old_map), StorageSlot::Map(new_map)) => {
+                for (key, value) in new_map.entries() {
+                    if old_map.get(key) != *value {
+                        storage_delta.set_map_item(index, *key, *value);
+                    }
+                }
+                // Removed entries are represented by setting them to the empty word
+                for (key, _) in old_map.entries() {
+                    if new_map.get(key) == Word::empty() {
+                        storage_delta.set_map_item(index, *key, Word::empty());
+                    }
+                }
+            }
+            _ => {
+                if old_slot.value() != new_slot.value() {
+                    storage_delta.set_item(index, new_slot.value());
+                }
+            }
+        }
+    }
+
+    let mut vault_delta = AccountVaultDelta::default();
+    for asset in after.vault().assets() {
+        match asset {
+            Asset::Fungible(fungible) => {
+                let old_balance = before
+                    .vault()
+                    .get_balance(fungible.faucet_id())
+                    .unwrap_or(0);
+                if fungible.amount() > old_balance {
+                    let added =
+                        FungibleAsset::new(fungible.faucet_id(), fungible.amount() - old_balance)?;
+                    vault_delta.add_asset(added.into())?;
+                }
+            }
+            Asset::NonFungible(_) => {
+                if !before.vault().has_non_fungible_asset(asset)? {
+                    vault_delta.add_asset(asset)?;
+                }
+            }
+        }
+    }
+    for asset in before.vault().assets() {
+        match asset {
+            Asset::Fungible(fungible) => {
+                let new_balance = after.vault().get_balance(fungible.faucet_id()).unwrap_or(0);
+                if fungible.amount() > new_balance {
+                    let removed =
+                        FungibleAsset::new(fungible.faucet_id(), fungible.amount() - new_balance)?;
+                    vault_delta.remove_asset(removed.into())?;
+                }
+            }
+            Asset::NonFungible(_) => {
+                if !after.vault().has_non_fungible_asset(asset)? {
+                    vault_delta.remove_asset(asset)?;
+                }
+            }
+        }
+    }
+
+    let nonce_delta = after.nonce() - before.nonce();
+    AccountDelta::new(after.id(), storage_delta, vault_delta, nonce_delta)
+        .context("Failed to build account delta")
+}
+
+/// Watches an account and invokes a callback with the delta of every committed state change
+///
+/// The client is synced every second, and whenever the account's state advanced, the callback
+/// receives the delta between the previous and the new state.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `account_id` - The ID of the account to watch
+/// * `on_change` - Callback invoked with each state delta
+/// * `cancel` - Token that stops the watch when cancelled
+/// * `max_duration` - The maximum time to watch for
+///
+/// # Errors
+/// Returns an error if the account is unknown to the client or syncing fails
+pub async fn watch_account(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    account_id: AccountId,
+    mut on_change: impl FnMut(&AccountDelta),
+    cancel: CancellationToken,
+    max_duration: Duration,
+) -> Result<()> {
+    let deadline = tokio::time::Instant::now() + max_duration;
+
+    let mut last_state = client
+        .get_account(account_id)
+        .await
+        .context("Failed to get account")?
+        .context("Account not found in client store")?
+        .account()
+        .clone();
+
+    loop {
+        tokio::select! {
+            _ = cancel.cancelled() => return Ok(()),
+            _ = tokio::time::sleep_until(deadline) => return Ok(()),
+            _ = tokio::time::sleep(SYNC_POLL_INTERVAL) => {}
+        }
+
+        client.sync_state().await.context("Failed to sync state")?;
+
+        let state = client
+            .get_account(account_id)
+            .await
+            .context("Failed to get account")?
+            .context("Account not found in client store")?
+            .account()
+            .clone();
+
+        if state.commitment() != last_state.commitment() {
+            let delta = account_delta_between(&last_state, &state)?;
+            on_change(&delta);
+            last_state = state;
+        }
+    }
+}
+
+/// Serializes a note as a `NoteFile` so it can be handed to another script or client out of band
+///
+/// The file carries the note details and tag, and lets the recipient look for the note in blocks
+/// after the client's current sync height, so export a note before or after publishing it.
+///
+/// # Arguments
+/// * `client` - The Miden client instance of the sender
+/// * `note` - The note to export
+///
+/// # Returns
+/// The serialized `NoteFile`, to be registered on the receiving side with `import_note`
+///
+/// # Errors
+/// Returns an error if the client's sync height can't be read
+pub async fn export_note(
+    client: &Client<FilesystemKeyStore<StdRng>>,
+    note: &Note,
+) -> Result<Vec<u8>> {
+    let after_block_num = client
+        .get_sync_height()
+        .await
+        .context("Failed to get sync height")?;
+
+    let note_file = NoteFile::NoteDetails {
+        details: NoteDetails::from(note.clone()),
+        after_block_num,
+        tag: Some(note.metadata().tag()),
+    };
+    Ok(note_file.to_bytes())
+}
+
+/// Registers a note file exported with `export_note` or `publish_private_note` with the client
+///
+/// Once imported, the note is tracked by the client and can be consumed with
+/// `consume_notes_by_id` after it has been committed on-chain.
+///
+/// # Arguments
+/// * `client` - The Miden client instance of the recipient
+/// * `bytes` - The serialized `NoteFile`
+///
+/// # Returns
+/// The ID of the imported note
+///
+/// # Errors
+/// Returns an error if the bytes aren't a valid note file or the import fails
+pub async fn import_note(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    bytes: &[u8],
+) -> Result<NoteId> {
+    let note_file = NoteFile::read_from_bytes(bytes).context(
+        "Failed to deserialize note file: the bytes are corrupt or were not produced by \
+         export_note",
+    )?;
+
+    client
+        .import_note(note_file)
+        .await
+        .context("Failed to import note")
+}
+
+/// Assembles inline MASM source into a note script
+///
+/// The source is assembled against the standard transaction kernel library, so it can use
+/// modules such as `miden::note` and `miden::account`.
+///
+/// # Arguments
+/// * `source` - The MASM source of the note script
+///
+/// # Returns
+/// The assembled `NoteScript`
+///
+/// # Errors
+/// Returns `ScriptError::Assembly` with the assembler report, which points at the failing
+/// lines of `source`
+pub fn note_script_from_masm(source: &str) -> Result<NoteScript> {
+    note_script_with_libs(source, &[])
+}
+
+/// Assembles inline MASM source into a note script that calls into compiled libraries
+///
+/// The libraries are linked dynamically, so the note script calls their procedures by MAST root
+/// and the code itself stays in the account that exports them, e.g. an account component
+/// package.
+///
+/// # Arguments
+/// * `source` - The MASM source of the note script
+/// * `libs` - The compiled library packages the script calls into
+///
+/// # Returns
+/// The assembled `NoteScript`
+///
+/// # Errors
+/// Returns `HelperError::UnexpectedPackageKind` if a package isn't a library, or
+/// `ScriptError::Assembly` with the assembler report, which also lists procedures not exported
+/// by any of `libs`
+pub fn note_script_with_libs(source: &str, libs: &[Arc<Package>]) -> Result<NoteScript> {
+    let assembly_error = |err| -> anyhow::Error {
+        ScriptError::Assembly {
+            report: format!("{:?}", anyhow::Error::from(err)),
+        }
+        .into()
+    };
+
+    let mut builder = ScriptBuilder::new(true);
+    for package in libs {
+        ensure_package_kind(package, PackageKind::Library)?;
+        builder = builder
+            .with_dynamically_linked_library(package.unwrap_library().as_ref())
+            .map_err(assembly_error)?;
+    }
+
+    builder.compile_note_script(source).map_err(assembly_error)
+}
+
+/// Creates a note whose script is assembled from inline MASM source
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `source` - The MASM source of the note script
+/// * `sender_id` - The ID of the account sending the note
+/// * `config` - Configuration for note creation
+///
+/// # Returns
+/// The created `Note`
+///
+/// # Errors
+/// Returns an error if the script fails to assemble, the config is invalid or note creation
+/// fails
+pub fn create_note_from_masm(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    source: &str,
+    sender_id: AccountId,
+    config: NoteCreationConfig,
+) -> Result<Note> {
+    let note_script = note_script_from_masm(source)?;
+    let serial_num = client.rng().draw_word();
+
+    note_from_script(note_script, serial_num, sender_id, config)
+}
+
+/// Returns the tag a note must carry to be discovered by the given account
+///
+/// Clients track the tags derived from the IDs of their accounts, so notes tagged this way are
+/// picked up when the target account's client syncs.
+///
+/// # Arguments
+/// * `account_id` - The ID of the target account
+/// * `note_type` - The type of the note
+///
+/// # Returns
+/// The `NoteTag` derived from the account ID, or `None` if the account can't receive notes of
+/// this type (network accounts only receive public notes)
+pub fn expected_tag_for(account_id: AccountId, note_type: NoteType) -> Option<NoteTag> {
+    let tag = NoteTag::from_account_id(account_id);
+    if tag.execution_mode() == NoteExecutionMode::Network && note_type != NoteType::Public {
+        return None;
+    }
+    Some(tag)
+}
+
+/// Asserts that a note is tagged so that the target account discovers it on the network
+///
+/// # Panics
+/// Panics if the account can't receive notes of this type or the note's tag differs from
+/// the one returned by `expected_tag_for`
+pub fn assert_note_routable_to(note: &Note, account_id: AccountId) {
+    let metadata = note.metadata();
+    let Some(expected) = expected_tag_for(account_id, metadata.note_type()) else {
+        panic!(
+            "Account {} can't receive {:?} notes",
+            account_id.to_hex(),
+            metadata.note_type()
+        );
+    };
+
+    assert_eq!(
+        metadata.tag(),
+        expected,
+        "Note {} is tagged {:?}, but account {} tracks {:?}",
+        note.id().to_hex(),
+        metadata.tag(),
+        account_id.to_hex(),
+        expected
+    );
+}
+
+/// Returns `config` switched to `account_type`, making sure the component supports that type
+fn with_account_type(
+    mut config: AccountCreationConfig,
+    account_type: AccountType,
+) -> AccountCreationConfig {
+    config.account_type = account_type;
+    match &mut config.supported_types {
+        Some(types) if !types.contains(&account_type) => types.push(account_type),
+        Some(_) => {}
+        None => config.supported_types = Some(vec![account_type]),
+    }
+    config
+}
+
+/// Creates an account with immutable code from a compiled package
+///
+/// Same as `create_account_from_package`, with the account type forced to
+/// `RegularAccountImmutableCode`.
+///
+/// # Errors
+/// Returns an error if account creation or client operations fail
+pub async fn create_immutable_account_from_package(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    package: Arc<Package>,
+    config: AccountCreationConfig,
+) -> Result<Account> {
+    let config = with_account_type(config, AccountType::RegularAccountImmutableCode);
+    create_account_from_package(client, package, config).await
+}
+
+/// Creates an account with updatable code from a compiled package
+///
+/// Same as `create_account_from_package`, with the account type forced to
+/// `RegularAccountUpdatableCode`.
+///
+/// # Errors
+/// Returns an error if account creation or client operations fail
+pub async fn create_updatable_account_from_package(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    package: Arc<Package>,
+    config: AccountCreationConfig,
+) -> Result<Account> {
+    let config = with_account_type(config, AccountType::RegularAccountUpdatableCode);
+    create_account_from_package(client, package, config).await
+}
+
+/// Asserts that an account's type forbids code updates
+///
+/// Only `RegularAccountUpdatableCode` accounts can update their code; regular immutable
+/// accounts and faucets can't.
+///
+/// # Panics
+/// Panics if the account has updatable code
+pub fn assert_code_immutable(account: &Account) {
+    assert_ne!(
+        account.account_type(),
+        AccountType::RegularAccountUpdatableCode,
+        "Account {} has updatable code",
+        account.id().to_hex()
+    );
+}
+
+/// Executes a transaction request against an account and submits it
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `account_id` - The ID of the account executing the transaction
+/// * `request` - The transaction request
+///
+/// # Returns
+/// The ID of the submitted transaction
+///
+/// # Errors
+/// Returns an error if the transaction fails to execute or submit
+pub async fn execute_and_submit(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    account_id: AccountId,
+    request: TransactionRequest,
+) -> Result<TransactionId> {
+    let tx_result = client
+        .new_transaction(account_id, request)
+        .await
+        .context("Failed to create transaction")?;
+
+    let tx_id = tx_result.executed_transaction().id();
+
+    client
+        .submit_transaction(tx_result)
+        .await
+        .context("Failed to submit transaction")?;
+
+    Ok(tx_id)
+}
+
+/// Executes and submits a batch of transactions, collecting one result per request
+///
+/// A failing request doesn't abort the batch. The client is synced before every transaction,
+/// unless `group_independent` is set, in which case it is only synced before a transaction whose
+/// account already has a transaction submitted since the last sync.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `requests` - The executing account and request of each transaction, in submission order
+/// * `group_independent` - Whether to skip syncs between transactions of different accounts
+///
+/// # Returns
+/// The result of each request, in the order of `requests`
+pub async fn submit_many(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    requests: Vec<(AccountId, TransactionRequest)>,
+    group_independent: bool,
+) -> Vec<Result<TransactionId>> {
+    let mut results = Vec::with_capacity(requests.len());
+    let mut accounts_since_sync = BTreeSet::new();
+
+    for (account_id, request) in requests {
+        let needs_sync = !group_independent || accounts_since_sync.contains(&account_id);
+        if needs_sync && !accounts_since_sync.is_empty() {
+            if let Err(err) = client.sync_state().await {
+                results.push(Err(anyhow::Error::from(err).context("Failed to sync state")));
+                continue;
+            }
+            accounts_since_sync.clear();
+        }
+
+        let result = execute_and_submit(client, account_id, request).await;
+        if result.is_ok() {
+            accounts_since_sync.insert(account_id);
+        }
+        results.push(result);
+    }
+
+    results
+}
+
+/// Builder for the assets of a note, which may come from several faucets
+///
+/// # Example
+/// ```ignore
+/// let config = NoteCreationConfig::default().with_assets(
+///     NoteAssetsBuilder::new()
+///         .add_fungible(faucet_a, 100)
+///         .add_fungible(faucet_b, 50),
+/// )?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct NoteAssetsBuilder {
+    fungible: Vec<(AccountId, u64)>,
+}
+
+impl NoteAssetsBuilder {
+    /// Creates an empty builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `amount` tokens of the fungible faucet `faucet_id`
+    pub fn add_fungible(mut self, faucet_id: AccountId, amount: u64) -> Self {
+        self.fungible.push((faucet_id, amount));
+        self
+    }
+
+    /// Builds the note assets
+    ///
+    /// # Errors
+    /// Returns an error if a faucet was added more than once, an amount is invalid, or the
+    /// number of assets exceeds the per-note limit
+    pub fn build(self) -> Result<NoteAssets> {
+        if self.fungible.len() > NoteAssets::MAX_NUM_ASSETS {
+            bail!(
+                "A note can carry at most {} assets, got {}",
+                NoteAssets::MAX_NUM_ASSETS,
+                self.fungible.len()
+            );
+        }
+
+        let mut faucets = BTreeSet::new();
+        let mut assets = Vec::with_capacity(self.fungible.len());
+        for (faucet_id, amount) in self.fungible {
+            if !faucets.insert(faucet_id) {
+                bail!(
+                    "Faucet {} was added more than once, merge its amounts instead",
+                    faucet_id.to_hex()
+                );
+            }
+
+            let asset =
+                FungibleAsset::new(faucet_id, amount).context("Failed to create fungible asset")?;
+            assets.push(asset.into());
+        }
+
+        NoteAssets::new(assets).context("Failed to create note assets")
+    }
+}
+
+/// Location of a named value in account storage
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageLocation {
+    /// A value slot
+    Value { slot: u8 },
+    /// An entry of a map slot
+    MapEntry { slot: u8, key: Word },
+}
+
+/// Named storage locations of an account, for reading storage as named values
+///
+/// # Example
+/// ```ignore
+/// let values = read_typed(&counter_account, &StorageSchema::counter())?;
+/// assert_eq!(values["count"], word_with_count_1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct StorageSchema {
+    fields: BTreeMap<String, StorageLocation>,
+}
+
+impl StorageSchema {
+    /// Creates an empty schema
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the schema of the counter contract, naming its count `"count"`
+    pub fn counter() -> Self {
+        Self::new().map_entry("count", 0, counter_count_key())
+    }
+
+    /// Registers a field stored in value slot `slot`
+    pub fn value(mut self, name: &str, slot: u8) -> Self {
+        self.fields
+            .insert(name.to_string(), StorageLocation::Value { slot });
+        self
+    }
+
+    /// Registers a field stored under `key` in map slot `slot`
+    pub fn map_entry(mut self, name: &str, slot: u8, key: Word) -> Self {
+        self.fields
+            .insert(name.to_string(), StorageLocation::MapEntry { slot, key });
+        self
+    }
+
+    /// Returns the registered fields and their locations
+    pub fn fields(&self) -> &BTreeMap<String, StorageLocation> {
+        &self.fields
+    }
+}
+
+/// Reads the fields of a storage schema from an account
+///
+/// # Arguments
+/// * `account` - The account to read from
+/// * `schema` - The named storage locations to read
+///
+/// # Returns
+/// The value of each field, keyed by name
+///
+/// # Errors
+/// Returns an error if a field references a slot the account doesn't have, or a slot of the
+/// wrong kind
+pub fn read_typed(account: &Account, schema: &StorageSchema) -> Result<BTreeMap<String, Word>> {
+    let storage = account.storage();
+    let num_slots = storage.slots().len();
+
+    schema
+        .fields()
+        .iter()
+        .map(|(name, location)| {
+            let (StorageLocation::Value { slot } | StorageLocation::MapEntry { slot, .. }) =
+                *location;
+            if usize::from(slot) >= num_slots {
+                bail!(
+                    "Field `{}` references storage slot {}, but the account has {} slots",
+                    name,
+                    slot,
+                    num_slots
+                );
+            }
+
+            let value = match *location {
+                StorageLocation::Value { slot } => storage.get_item(slot),
+                StorageLocation::MapEntry { slot, key } => storage.get_map_item(slot, key),
+            }
+            .context(format!("Failed to read field `{name}`"))?;
+
+            Ok((name.clone(), value))
+        })
+        .collect()
+}
+
+/// Classified reason a transaction was rejected, carrying the rendered error chain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TxFailure {
+    /// The transaction program failed, e.g. because a consumed note's script asserted
+    NoteScriptFailed(String),
+    /// The account doesn't hold the assets the transaction moves
+    InsufficientAssets(String),
+    /// The transaction was built against a stale account nonce
+    NonceMismatch(String),
+    /// The authentication procedure rejected the transaction
+    AuthFailed(String),
+    /// An input note was already consumed
+    NoteAlreadyConsumed(String),
+    /// The transaction was executed locally but the node rejected its submission
+    NodeRejected(String),
+    /// Any other failure
+    Other(String),
+}
+
+impl TxFailure {
+    /// Returns the rendered error chain of the failure
+    pub fn message(&self) -> &str {
+        match self {
+            Self::NoteScriptFailed(message)
+            | Self::InsufficientAssets(message)
+            | Self::NonceMismatch(message)
+            | Self::AuthFailed(message)
+            | Self::NoteAlreadyConsumed(message)
+            | Self::NodeRejected(message)
+            | Self::Other(message) => message,
+        }
+    }
+
+    /// Classifies a failed execution or submission by the typed errors in its error chain
+    ///
+    /// Only the client and executor errors are inspected, never the rendered messages, so the
+    /// helpers' own `.context()` strings can't affect the classification.
+    fn classify(error: &anyhow::Error) -> Self {
+        let message = format!("{error:#}");
+
+        // The authenticator's error is wrapped in an execution error, so look for it first
+        if error
+            .chain()
+            .any(|cause| cause.downcast_ref::<AuthenticationError>().is_some())
+        {
+            return Self::AuthFailed(message);
+        }
+
+        if error.chain().any(|cause| {
+            matches!(
+                cause.downcast_ref::<ClientError>(),
+                Some(ClientError::TransactionRequestError(
+                    TransactionRequestError::InputNoteAlreadyConsumed(_)
+                ))
+            )
+        }) {
+            return Self::NoteAlreadyConsumed(message);
+        }
+
+        // Submission is the only step that talks to the node after local execution succeeded
+        if error.chain().any(|cause| {
+            cause.downcast_ref::<RpcError>().is_some()
+                || matches!(
+                    cause.downcast_ref::<ClientError>(),
+                    Some(ClientError::RpcError(_))
+                )
+        }) {
+            return Self::NodeRejected(message);
+        }
+
+        let executor_error =
+            error
+                .chain()
+                .find_map(|cause| match cause.downcast_ref::<ClientError>() {
+                    Some(ClientError::TransactionExecutorError(err)) => Some(err),
+                    _ => cause.downcast_ref::<TransactionExecutorError>(),
+                });
+
+        match executor_error {
+            Some(TransactionExecutorError::Unauthorized(_)) => Self::AuthFailed(message),
+            Some(TransactionExecutorError::InconsistentAccountNonceDelta { .. }) => {
+                Self::NonceMismatch(message)
+            }
+            Some(TransactionExecutorError::TransactionProgramExecutionFailed(_)) => {
+                match failed_assertion_code(error) {
+                    Some(code) if INSUFFICIENT_ASSETS_ERRORS.iter().any(|e| e.code() == code) => {
+                        Self::InsufficientAssets(message)
+                    }
+                    _ => Self::NoteScriptFailed(message),
+                }
+            }
+            _ => Self::Other(message),
+        }
+    }
+}
+
+/// Kernel errors raised when an account vault doesn't hold the assets a transaction removes
+const INSUFFICIENT_ASSETS_ERRORS: &[MasmError] = &[
+    ERR_VAULT_FUNGIBLE_ASSET_AMOUNT_LESS_THAN_AMOUNT_TO_WITHDRAW,
+    ERR_VAULT_NON_FUNGIBLE_ASSET_TO_REMOVE_NOT_FOUND,
+];
+
+/// Returns the error code of the assertion that failed while executing a transaction, if its
+/// execution failed on an assertion
+fn failed_assertion_code(error: &anyhow::Error) -> Option<Felt> {
+    error
+        .chain()
+        .find_map(|cause| match cause.downcast_ref::<ExecutionError>() {
+            Some(ExecutionError::FailedAssertion { err_code, .. }) => Some(*err_code),
+            _ => None,
+        })
+}
+
+/// Executes and submits a transaction that is expected to be rejected
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `account_id` - The ID of the account executing the transaction
+/// * `request` - The transaction request
+///
+/// # Returns
+/// The classified reason the transaction was rejected
+///
+/// # Panics
+/// Panics with the transaction ID if the transaction is executed and submitted successfully
+pub async fn expect_tx_failure(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    account_id: AccountId,
+    request: TransactionRequest,
+) -> TxFailure {
+    match execute_and_submit(client, account_id, request).await {
+        Ok(tx_id) => panic!(
+            "expected transaction for account {} to fail, but {} was submitted",
+            account_id.to_hex(),
+            tx_id.to_hex()
+        ),
+        Err(err) => TxFailure::classify(&err),
+    }
+}
+
+/// Summary of an account's state, as stored in the client
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountSummary {
+    pub id: AccountId,
+    pub nonce: u64,
+    pub storage_slots: usize,
+    pub vault_assets: usize,
+}
+
+/// Lists every account stored in the client
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+///
+/// # Returns
+/// The ID, type and storage mode of each account, or an empty vec for a fresh store
+pub async fn list_accounts(
+    client: &Client<FilesystemKeyStore<StdRng>>,
+) -> Result<Vec<(AccountId, AccountType, AccountStorageMode)>> {
+    let headers = client
+        .get_account_headers()
+        .await
+        .context("Failed to get account headers")?;
+
+    Ok(headers
+        .into_iter()
+        .map(|(header, _)| {
+            let id = header.id();
+            (id, id.account_type(), id.storage_mode())
+        })
+        .collect())
+}
+
+/// Summarizes the state of an account stored in the client
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `account_id` - The ID of the account
+///
+/// # Returns
+/// The account's nonce, storage slot count and vault asset count
+///
+/// # Errors
+/// Returns an error if the account isn't in the client store
+pub async fn account_summary(
+    client: &Client<FilesystemKeyStore<StdRng>>,
+    account_id: AccountId,
+) -> Result<AccountSummary> {
+    let record = client
+        .get_account(account_id)
+        .await
+        .context("Failed to get account")?
+        .context("Account not found in client store")?;
+    let account = record.account();
+
+    Ok(AccountSummary {
+        id: account_id,
+        nonce: account.nonce().as_int(),
+        storage_slots: account.storage().slots().len(),
+        vault_assets: account.vault().assets().count(),
+    })
+}
+
+/// Accepted names of each account storage mode, in `AccountStorageMode` order
+const STORAGE_MODE_NAMES: &[&str] = &["public", "private", "network"];
+
+/// Accepted names of each account type, in `AccountType` order
+const ACCOUNT_TYPE_NAMES: &[&str] = &[
+    "fungible-faucet",
+    "non-fungible-faucet",
+    "regular-immutable",
+    "regular-updatable",
+];
+
+/// Parses an account storage mode from user input, ignoring case
+///
+/// # Arguments
+/// * `s` - One of "public", "private" or "network"
+///
+/// # Errors
+/// Returns `HelperError::UnrecognizedOption` listing the valid names
+pub fn parse_storage_mode(s: &str) -> Result<AccountStorageMode> {
+    match s.trim().to_ascii_lowercase().as_str() {
+        "public" => Ok(AccountStorageMode::Public),
+        "private" => Ok(AccountStorageMode::Private),
+        "network" => Ok(AccountStorageMode::Network),
+        _ => Err(HelperError::UnrecognizedOption {
+            kind: "storage mode",
+            value: s.to_string(),
+            valid: STORAGE_MODE_NAMES,
+        }
+        .into()),
+    }
+}
+
+/// Parses an account type from user input, ignoring case and treating `_` as `-`
+///
+/// # Arguments
+/// * `s` - One of "fungible-faucet", "non-fungible-faucet", "regular-immutable" or
+///   "regular-updatable"
+///
+/// # Errors
+/// Returns `HelperError::UnrecognizedOption` listing the valid names
+pub fn parse_account_type(s: &str) -> Result<AccountType> {
+    match s.trim().to_ascii_lowercase().replace('_', "-").as_str() {
+        "fungible-faucet" => Ok(AccountType::FungibleFaucet),
+        "non-fungible-faucet" => Ok(AccountType::NonFungibleFaucet),
+        "regular-immutable" => Ok(AccountType::RegularAccountImmutableCode),
+        "regular-updatable" => Ok(AccountType::RegularAccountUpdatableCode),
+        _ => Err(HelperError::UnrecognizedOption {
+            kind: "account type",
+            value: s.to_string(),
+            valid: ACCOUNT_TYPE_NAMES,
+        }
+        .into()),
+    }
+}
+
+/// Creates a note from a compiled package that only `target` may consume
+///
+/// The target account ID is prepended to `config.inputs` as `[suffix, prefix]`, the encoding
+/// used by the P2ID note script, so the note script can check it against the consuming account.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `package` - The compiled package containing the note script
+/// * `sender_id` - The ID of the account sending the note
+/// * `target` - The ID of the only account allowed to consume the note
+/// * `config` - Configuration for note creation
+///
+/// # Returns
+/// The created `Note`
+///
+/// # Errors
+/// Returns an error if the package isn't a program, the config is invalid or note creation fails
+pub fn create_note_for_account(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    package: Arc<Package>,
+    sender_id: AccountId,
+    target: AccountId,
+    mut config: NoteCreationConfig,
+) -> Result<Note> {
+    let mut inputs = NoteInputsBuilder::default().push_account_id(target).build();
+    inputs.append(&mut config.inputs);
+    config.inputs = inputs;
+
+    create_note_from_package(client, package, sender_id, config)
+}
+
+/// Asserts that a note's inputs start with the encoding of `target`
+///
+/// # Panics
+/// Panics if the first two note inputs aren't `[target.suffix(), target.prefix()]`
+pub fn assert_consumable_only_by(note: &Note, target: AccountId) {
+    let expected = NoteInputsBuilder::default().push_account_id(target).build();
+    let inputs = note.inputs().values();
+
+    assert!(
+        inputs.starts_with(&expected),
+        "note {} doesn't target account {}: inputs start with {:?}",
+        note.id().to_hex(),
+        target.to_hex(),
+        &inputs[..inputs.len().min(expected.len())]
+    );
+}
+
+/// Returns an account's commitment, the hash of its ID, nonce, vault, storage and code
+pub fn account_commitment(account: &Account) -> Word {
+    account.commitment()
+}
+
+/// Asserts that a transaction changed an account's committed state
+///
+/// # Panics
+/// Panics if `before` and `after` have the same commitment
+pub fn assert_commitment_changed(before: &Account, after: &Account) {
+    let commitment = account_commitment(before);
+    assert_ne!(
+        commitment,
+        account_commitment(after),
+        "commitment of account {} is unchanged at {}",
+        after.id().to_hex(),
+        commitment.to_hex()
+    );
+}
+
+/// Syncs the client and returns the newly discovered notes tagged for an account
+///
+/// The tag derived from the account ID is registered with the client first if it isn't tracked
+/// yet, so notes addressed to the account are fetched during the sync.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `account_id` - The ID of the account whose notes to look for
+///
+/// # Returns
+/// The IDs of the public notes discovered by this sync that carry the account's tag
+pub async fn sync_account_notes(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    account_id: AccountId,
+) -> Result<Vec<NoteId>> {
+    let tag = NoteTag::from_account_id(account_id);
+
+    add_tracked_tag(client, tag).await?;
+
+    let summary = client.sync_state().await.context("Failed to sync state")?;
+
+    let mut note_ids = Vec::new();
+    for note_id in summary.new_public_notes {
+        let record = client
+            .get_input_note(note_id)
+            .await
+            .context("Failed to get input note")?;
+        let tagged = record
+            .as_ref()
+            .and_then(|record| record.metadata())
+            .is_some_and(|metadata| metadata.tag() == tag);
+        if tagged {
+            note_ids.push(note_id);
+        }
+    }
+
+    Ok(note_ids)
+}
+
+/// Consumes input notes and emits output notes in a single transaction
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `account_id` - The ID of the account executing the transaction
+/// * `inputs` - The notes to consume, passed as unauthenticated input notes
+/// * `outputs` - The notes to emit, which must all be sent by `account_id`
+///
+/// # Returns
+/// The ID of the submitted transaction and the delta it applied to the account
+///
+/// # Errors
+/// Returns an error if an output note has another sender, or if the transaction fails to
+/// execute or submit
+pub async fn consume_and_emit(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    account_id: AccountId,
+    inputs: Vec<Note>,
+    outputs: Vec<OutputNote>,
+) -> Result<(TransactionId, AccountDelta)> {
+    if let Some(note) = outputs
+        .iter()
+        .find(|note| note.metadata().sender() != account_id)
+    {
+        bail!(
+            "Output note {} is sent by {}, expected {}",
+            note.id().to_hex(),
+            note.metadata().sender().to_hex(),
+            account_id.to_hex()
+        );
+    }
+
+    let request = TransactionRequestBuilder::new()
+        .unauthenticated_input_notes(inputs.into_iter().map(|note| (note, None)))
+        .own_output_notes(outputs)
+        .build()
+        .context("Failed to build consume and emit transaction request")?;
+
+    let tx_result = client
+        .new_transaction(account_id, request)
+        .await
+        .context("Failed to create consume and emit transaction")?;
+
+    let tx_id = tx_result.executed_transaction().id();
+    let delta = tx_result.executed_transaction().account_delta().clone();
+
+    client
+        .submit_transaction(tx_result)
+        .await
+        .context("Failed to submit consume and emit transaction")?;
+
+    Ok((tx_id, delta))
+}
+
+/// Name and MAST root of a procedure exported by a library package
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcedureInfo {
+    /// The procedure's name, as used by `call.<name>` once its module is imported
+    pub name: String,
+    /// The fully qualified path of the procedure, including its module
+    pub path: String,
+    /// The MAST root of the procedure, which transaction scripts can `call` directly
+    pub root: Word,
+}
+
+/// Lists the procedures exported by a library or account component package
+///
+/// # Arguments
+/// * `package` - The compiled package
+///
+/// # Returns
+/// The exported procedures, sorted by path
+///
+/// # Errors
+/// Returns `HelperError::UnexpectedPackageKind` if the package is a program
+pub fn account_procedures(package: &Package) -> Result<Vec<ProcedureInfo>> {
+    ensure_package_kind(package, PackageKind::Library)?;
+    let library = package.unwrap_library();
+
+    let mut procedures: Vec<_> = library
+        .exports()
+        .map(|export| {
+            let node_id = library.get_export_node_id(export);
+            ProcedureInfo {
+                name: export.name.as_str().to_string(),
+                path: export.to_string(),
+                root: library.mast_forest()[node_id].digest(),
+            }
+        })
+        .collect();
+    procedures.sort_by(|a, b| a.path.cmp(&b.path));
+
+    Ok(procedures)
+}
+
+/// Executes a transaction, measuring how long execution takes
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `account_id` - The ID of the account executing the transaction
+/// * `request` - The transaction request
+///
+/// # Returns
+/// The executed transaction, ready for `timed_submit`, and the execution time
+///
+/// # Errors
+/// Returns an error if the transaction fails to execute
+pub async fn timed_execute(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    account_id: AccountId,
+    request: TransactionRequest,
+) -> Result<(TransactionResult, Duration)> {
+    let start = Instant::now();
+    let tx_result = client
+        .new_transaction(account_id, request)
+        .await
+        .context("Failed to create transaction")?;
+    let elapsed = start.elapsed();
+
+    tracing::debug!(
+        tx_id = %tx_result.executed_transaction().id().to_hex(),
+        ?elapsed,
+        "executed transaction"
+    );
+
+    Ok((tx_result, elapsed))
+}
+
+/// Proves and submits an executed transaction, measuring how long both take
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `tx_result` - The executed transaction
+///
+/// # Returns
+/// The ID of the submitted transaction and the combined proving and submission time
+///
+/// # Errors
+/// Returns an error if the transaction fails to prove or submit
+pub async fn timed_submit(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    tx_result: TransactionResult,
+) -> Result<(TransactionId, Duration)> {
+    let tx_id = tx_result.executed_transaction().id();
+
+    let start = Instant::now();
+    client
+        .submit_transaction(tx_result)
+        .await
+        .context("Failed to submit transaction")?;
+    let elapsed = start.elapsed();
+
+    tracing::debug!(tx_id = %tx_id.to_hex(), ?elapsed, "proved and submitted transaction");
+
+    Ok((tx_id, elapsed))
+}
+
+/// Creates an increment note addressed to a specific counter account
+///
+/// The counter ID is encoded at the start of the note inputs like `create_note_for_account`
+/// does, so a note script checking it against the consuming account only affects that counter.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `note_package` - The compiled increment note package
+/// * `sender_id` - The ID of the account sending the note
+/// * `counter_id` - The ID of the counter account the note increments
+/// * `config` - Configuration for note creation
+///
+/// # Returns
+/// The created `Note`
+///
+/// # Errors
+/// Returns an error if the package isn't a program, the config is invalid or note creation fails
+pub fn create_increment_note(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    note_package: Arc<Package>,
+    sender_id: AccountId,
+    counter_id: AccountId,
+    config: NoteCreationConfig,
+) -> Result<Note> {
+    create_note_for_account(client, note_package, sender_id, counter_id, config)
+}
+
+/// Name under which packages declare their dependency on the protocol library
+const PROTOCOL_LIBRARY_NAME: &str = "miden";
+
+/// Checks that a package was linked against the protocol library the client executes with
+///
+/// Packages don't declare a kernel version, but a package compiled with another toolchain
+/// records a different digest for its protocol library dependency, which would otherwise only
+/// surface as a confusing failure when executing its procedures.
+///
+/// # Arguments
+/// * `package` - The compiled package
+///
+/// # Errors
+/// Returns `HelperError::IncompatiblePackage` with both digests on mismatch
+pub fn assert_package_compatible(package: &Package) -> Result<()> {
+    let expected = *MidenLib::default().as_ref().digest();
+
+    for dependency in package.manifest.dependencies() {
+        if dependency.name.as_ref() == PROTOCOL_LIBRARY_NAME && dependency.digest != expected {
+            return Err(HelperError::IncompatiblePackage {
+                dependency: dependency.name.to_string(),
+                found: dependency.digest,
+                expected,
+            }
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Suffix of the storage entry name under which auth components declare their public key
+const PUBLIC_KEY_ENTRY_SUFFIX: &str = "pub_key";
+
+/// Creates an account whose only component is a custom auth component from a compiled package
+///
+/// If the component declares a storage entry named `*pub_key`, a Falcon key pair is generated,
+/// its public key is written to that slot and the secret key is added to the keystore. Other
+/// auth components are created with `config.storage_slots` as is and no key is registered.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `keystore` - The keystore to add the generated key to
+/// * `auth_package` - The compiled package containing the auth component
+/// * `config` - Configuration for account creation
+///
+/// # Returns
+/// The created `Account`
+///
+/// # Errors
+/// Returns an error if the package isn't an account component, or if account creation or
+/// client operations fail
+pub async fn create_auth_only_account(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    keystore: Arc<FilesystemKeyStore<StdRng>>,
+    auth_package: Arc<Package>,
+    mut config: AccountCreationConfig,
+) -> Result<Account> {
+    let metadata = component_metadata(&auth_package)?;
+
+    let key_slot = metadata
+        .storage_entries()
+        .find(|entry| {
+            entry
+                .name()
+                .is_some_and(|name| name.as_str().ends_with(PUBLIC_KEY_ENTRY_SUFFIX))
+        })
+        .and_then(|entry| entry.slot_indices().next());
+
+    let key_pair = key_slot.map(|slot| {
+        let key_pair = SecretKey::with_rng(client.rng());
+        let slot = slot as usize;
+        if config.storage_slots.len() <= slot {
+            config
+                .storage_slots
+                .resize(slot + 1, StorageSlot::empty_value());
+        }
+        config.storage_slots[slot] = StorageSlot::Value(key_pair.public_key().into());
+        key_pair
+    });
+
+    let auth_component = account_component_from_metadata(metadata, &auth_package, &config)
+        .context("Failed to create auth component from package")?;
+
+    let mut init_seed = [0_u8; 32];
+    client.rng().fill_bytes(&mut init_seed);
+
+    let (account, seed) = AccountBuilder::new(init_seed)
+        .account_type(config.account_type)
+        .storage_mode(config.storage_mode)
+        .with_auth_component(auth_component)
+        .build()
+        .context("Failed to build account")?;
+
+    client
+        .add_account(&account, Some(seed), false)
+        .await
+        .context("Failed to add account to client")?;
+
+    if let Some(key_pair) = key_pair {
+        keystore
+            .add_key(&AuthSecretKey::RpoFalcon512(key_pair))
+            .context("Failed to add key to keystore")?;
+    }
+
+    Ok(account)
+}
+
+/// How a storage slot changed between two states of an account
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SlotChange {
+    /// A value slot holding a different word
+    Value { before: Word, after: Word },
+    /// A map slot with changed entries, as `(key, before, after)`. Absent entries read as the
+    /// empty word
+    Map { entries: Vec<(Word, Word, Word)> },
+    /// A slot that switched between a value and a map
+    Kind,
+}
+
+/// Field-by-field difference between two states of an account
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccountStateDiff {
+    pub nonce_before: u64,
+    pub nonce_after: u64,
+    /// Indices of the slots only present in the `after` state
+    pub added_slots: Vec<usize>,
+    /// Indices of the slots only present in the `before` state
+    pub removed_slots: Vec<usize>,
+    /// Slots present in both states whose contents differ, keyed by index
+    pub changed_slots: BTreeMap<usize, SlotChange>,
+    /// Change of each fungible balance, keyed by faucet ID
+    pub fungible_deltas: BTreeMap<AccountId, i128>,
+    /// Non-fungible assets only present in the `after` state
+    pub added_assets: Vec<Asset>,
+    /// Non-fungible assets only present in the `before` state
+    pub removed_assets: Vec<Asset>,
+}
+
+impl AccountStateDiff {
+    /// Returns `true` if the two states are identical
+    pub fn is_empty(&self) -> bool {
+        self.nonce_before == self.nonce_after
+            && self.added_slots.is_empty()
+            && self.removed_slots.is_empty()
+            && self.changed_slots.is_empty()
+            && self.fungible_deltas.is_empty()
+            && self.added_assets.is_empty()
+            && self.removed_assets.is_empty()
+    }
+}
+
+/// Compares two states of an account field by field
+///
+/// Unlike a transaction's account delta, this only needs the two account snapshots.
+///
+/// # Arguments
+/// * `before` - The earlier state of the account
+/// * `after` - The later state of the account
+///
+/// # Returns
+/// The nonce change, storage slot changes and vault changes between both states
+pub fn account_state_diff(before: &Account, after: &Account) -> AccountStateDiff {
+    let mut diff = AccountStateDiff {
+        nonce_before: before.nonce().as_int(),
+        nonce_after: after.nonce().as_int(),
+        ..Default::default()
+    };
+
+    let old_slots = before.storage().slots();
+    let new_slots = after.storage().slots();
+    diff.added_slots = (old_slots.len()..new_slots.len()).collect();
+    diff.removed_slots = (new_slots.len()..old_slots.len()).collect();
+
+    for (index, (old_slot, new_slot)) in old_slots.iter().zip(new_slots).enumerate() {
+        let change = match (old_slot, new_slot) {
+            (StorageSlot::Map(old_map), StorageSlot::Map(new_map)) => {
+                let keys: BTreeSet<Word> = old_map
+                    .entries()
+                    .chain(new_map.entries())
+                    .map(|(key, _)| *key)
+                    .collect();
+                let entries: Vec<_> = keys
+                    .into_iter()
+                    .filter_map(|key| {
+                        let (old_value, new_value) = (old_map.get(&key), new_map.get(&key));
+                        (old_value != new_value).then_some((key, old_value, new_value))
+                    })
+                    .collect();
+                (!entries.is_empty()).then_some(SlotChange::Map { entries })
+            }
+            (StorageSlot::Map(_), _) | (_, StorageSlot::Map(_)) => Some(SlotChange::Kind),
+            _ => (old_slot.value() != new_slot.value()).then(|| SlotChange::Value {
+                before: old_slot.value(),
+                after: new_slot.value(),
+            }),
+        };
+        if let Some(change) = change {
+            diff.changed_slots.insert(index, change);
+        }
+    }
+
+    let faucet_ids: BTreeSet<_> = before
+        .vault()
+        .assets()
+        .chain(after.vault().assets())
+        .filter_map(|asset| match asset {
+            Asset::Fungible(fungible) => Some(fungible.faucet_id()),
+            Asset::NonFungible(_) => None,
+        })
+        .collect();
+    for faucet_id in faucet_ids {
+        let old_balance = before.vault().get_balance(faucet_id).unwrap_or(0);
+        let new_balance = after.vault().get_balance(faucet_id).unwrap_or(0);
+        if old_balance != new_balance {
+            diff.fungible_deltas
+                .insert(faucet_id, i128::from(new_balance) - i128::from(old_balance));
+        }
+    }
+
+    let non_fungible = |account: &Account| -> Vec<Asset> {
+        account
+            .vault()
+            .assets()
+            .filter(|asset| matches!(asset, Asset::NonFungible(_)))
+            .collect()
+    };
+    let (old_assets, new_assets) = (non_fungible(before), non_fungible(after));
+    diff.added_assets = new_assets
+        .iter()
+        .filter(|asset| !old_assets.contains(asset))
+        .copied()
+        .collect();
+    diff.removed_assets = old_assets
+        .iter()
+        .filter(|asset| !new_assets.contains(asset))
+        .copied()
+        .collect();
+
+    diff
+}
+
+/// Writes an executed transaction to disk for post-mortem inspection
+///
+/// The whole `TransactionResult` is serialized, so the transaction ID, account delta, consumed
+/// notes and created notes can all be recovered with `load_tx_dump`.
+///
+/// # Arguments
+/// * `tx_result` - The executed transaction
+/// * `path` - The file to write
+///
+/// # Errors
+/// Returns an error if the file can't be written
+pub fn dump_tx_result(tx_result: &TransactionResult, path: &Path) -> std::io::Result<()> {
+    std::fs::write(path, tx_result.to_bytes())
+}
+
+/// Loads a transaction written with `dump_tx_result`
+///
+/// # Arguments
+/// * `path` - The file to read
+///
+/// # Returns
+/// The deserialized `TransactionResult`
+///
+/// # Errors
+/// Returns an error if the file can't be read or deserialized
+pub fn load_tx_dump(path: &Path) -> Result<TransactionResult> {
+    let bytes = std::fs::read(path).context(format!(
+        "Failed to read transaction dump {}",
+        path.display()
+    ))?;
+
+    TransactionResult::read_from_bytes(&bytes)
+        .context("Failed to deserialize transaction result from bytes")
+}
+
+/// Asserts that the node rejects a note that is consumed twice
+///
+/// The note is consumed once, which must succeed and be committed, then consumed again as an
+/// unauthenticated note by a second client. The second client never saw the first consumption,
+/// so it executes the transaction locally and only the node's nullifier check can reject it.
+///
+/// # Arguments
+/// * `client` - The Miden client instance consuming the note first
+/// * `account_id` - The ID of the account consuming the note first
+/// * `second` - A second client on the same node, with its own store
+/// * `second_account_id` - The ID of the account of `second` consuming the note again
+/// * `note` - The note to consume
+///
+/// # Errors
+/// Returns an error if the first consumption fails or isn't committed, or if the node can't be
+/// queried for the note's nullifier
+///
+/// # Panics
+/// Panics if the second consumption succeeds, isn't rejected by the node, or is rejected while
+/// the node hasn't recorded the note's nullifier
+pub async fn assert_double_spend_rejected(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    account_id: AccountId,
+    second: &mut ClientSetup,
+    second_account_id: AccountId,
+    note: Note,
+) -> Result<()> {
+    let note_id = note.id();
+
+    let tx_id = consume_notes(client, account_id, std::slice::from_ref(&note))
+        .await
+        .context("First consumption of the note failed")?;
+    wait_for_transaction(client, tx_id, DEFAULT_MAX_BLOCKS)
+        .await
+        .context("First consumption of the note wasn't committed")?;
+
+    let request = TransactionRequestBuilder::new()
+        .unauthenticated_input_notes([(note.clone(), None)])
+        .build()
+        .context("Failed to build consume note transaction request")?;
+    let failure = expect_tx_failure(&mut second.client, second_account_id, request).await;
+
+    assert!(
+        matches!(failure, TxFailure::NodeRejected(_)),
+        "second consumption of note {} wasn't rejected by the node: {failure:?}",
+        note_id.to_hex()
+    );
+    assert!(
+        is_nullifier_spent(second.rpc_api.as_ref(), note.nullifier()).await?,
+        "node rejected the second consumption of note {}, but hasn't recorded its nullifier: \
+         {failure:?}",
+        note_id.to_hex()
+    );
+
+    Ok(())
+}
+
+/// Creates an account with private storage from a compiled package
+///
+/// Same as `create_account_from_package`, with the storage mode forced to `Private`. Only a
+/// commitment to the account state is published once the account's first transaction is
+/// committed.
+///
+/// # Errors
+/// Returns an error if account creation or client operations fail
+pub async fn create_private_account_from_package(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    package: Arc<Package>,
+    config: AccountCreationConfig,
+) -> Result<Account> {
+    let config = AccountCreationConfig {
+        storage_mode: AccountStorageMode::Private,
+        ..config
+    };
+    create_account_from_package(client, package, config).await
+}
+
+/// Fetches the details the node serves for an account
+async fn fetch_account_details(
+    rpc_api: &dyn NodeRpcClient,
+    account_id: AccountId,
+) -> Result<FetchedAccount> {
+    rpc_api
+        .get_account_details(account_id)
+        .await
+        .context(format!(
+            "Failed to fetch account {} from the node",
+            account_id.to_hex()
+        ))
+}
+
+/// Asserts that the node serves an account's full state, as it does for public accounts
+///
+/// The account must have at least one committed transaction to be known to the node.
+///
+/// # Arguments
+/// * `rpc_api` - The RPC API of the client under test, i.e. `ClientSetup::rpc_api`
+/// * `account_id` - The ID of the account
+///
+/// # Errors
+/// Returns an error if the node can't be queried or doesn't know the account
+///
+/// # Panics
+/// Panics if the node only serves a commitment to the account state
+pub async fn assert_storage_public(
+    rpc_api: &dyn NodeRpcClient,
+    account_id: AccountId,
+) -> Result<()> {
+    let details = fetch_account_details(rpc_api, account_id).await?;
+    assert!(
+        matches!(details, FetchedAccount::Public(..)),
+        "node doesn't serve the state of account {}",
+        account_id.to_hex()
+    );
+    Ok(())
+}
+
+/// Asserts that the node only serves a commitment to an account's state, as it does for private
+/// accounts
+///
+/// The account must have at least one committed transaction to be known to the node.
+///
+/// # Arguments
+/// * `rpc_api` - The RPC API of the client under test, i.e. `ClientSetup::rpc_api`
+/// * `account_id` - The ID of the account
+///
+/// # Errors
+/// Returns an error if the node can't be queried or doesn't know the account
+///
+/// # Panics
+/// Panics if the node serves the account's full state
+pub async fn assert_storage_private(
+    rpc_api: &dyn NodeRpcClient,
+    account_id: AccountId,
+) -> Result<()> {
+    let details = fetch_account_details(rpc_api, account_id).await?;
+    assert!(
+        matches!(details, FetchedAccount::Private(..)),
+        "node serves the state of account {}",
+        account_id.to_hex()
+    );
+    Ok(())
+}
+
+/// Syncs the client until every transaction in `tx_ids` is committed
+///
+/// Each sync advances all transactions at once, which is faster than waiting for them one by
+/// one with `wait_for_transaction`, e.g. after a `submit_many` batch.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `tx_ids` - The IDs of the transactions to wait for
+/// * `max_blocks` - The number of blocks to wait before giving up
+///
+/// # Errors
+/// Returns an error if syncing fails, a transaction is discarded, or some transactions aren't
+/// committed within `max_blocks` blocks, listing those transactions
+pub async fn wait_for_all(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    tx_ids: &[TransactionId],
+    max_blocks: u32,
+) -> Result<()> {
+    let start_block = client
+        .sync_state()
+        .await
+        .context("Failed to sync state")?
+        .block_num
+        .as_u32();
+
+    let mut pending: BTreeSet<TransactionId> = tx_ids.iter().copied().collect();
+
+    loop {
+        let records = client
+            .get_transactions(TransactionFilter::Ids(pending.iter().copied().collect()))
+            .await
+            .context("Failed to get transaction records")?;
+
+        for record in records {
+            match &record.status {
+                TransactionStatus::Committed { .. } => {
+                    pending.remove(&record.id);
+                }
+                TransactionStatus::Discarded(cause) => {
+                    bail!(
+                        "Transaction {} was discarded: {:?}",
+                        record.id.to_hex(),
+                        cause
+                    )
+                }
+                _ => {}
+            }
+        }
+
+        if pending.is_empty() {
+            return Ok(());
+        }
+
+        tokio::time::sleep(SYNC_POLL_INTERVAL).await;
+
+        let block_num = client
+            .sync_state()
+            .await
+            .context("Failed to sync state")?
+            .block_num
+            .as_u32();
+
+        if block_num.saturating_sub(start_block) > max_blocks {
+            let pending: Vec<_> = pending.iter().map(TransactionId::to_hex).collect();
+            bail!(
+                "Transactions {} were not committed within {} blocks",
+                pending.join(", "),
+                max_blocks
+            );
+        }
+    }
+}
+
+/// Returns the nullifier a note publishes when it is consumed
+///
+/// The nullifier only depends on the note itself, so it is the same whichever account consumes
+/// the note.
+pub fn note_nullifier(note: &Note) -> Nullifier {
+    note.nullifier()
+}
+
+/// Checks whether the node has recorded a nullifier, i.e. whether its note was consumed
+///
+/// # Arguments
+/// * `rpc_api` - The RPC API of the client under test, i.e. `ClientSetup::rpc_api`
+/// * `nullifier` - The nullifier to look up
+///
+/// # Errors
+/// Returns an error if the node can't be queried
+pub async fn is_nullifier_spent(rpc_api: &dyn NodeRpcClient, nullifier: Nullifier) -> Result<bool> {
+    let commit_height = rpc_api
+        .get_nullifier_commit_height(&nullifier, BlockNumber::GENESIS)
+        .await
+        .context(format!(
+            "Failed to look up nullifier {}",
+            nullifier.to_hex()
+        ))?;
+
+    Ok(commit_height.is_some())
+}
+
+/// Builds a word holding `value` in its last element, e.g. `[0, 0, 0, value]`
+///
+/// Words are written most significant element first throughout the helpers and contracts, so
+/// the last element (index 3) is the least significant one. This is the layout of the counter's
+/// storage key and count.
+pub fn word_from_u64(value: u64) -> Word {
+    word_from_felts(Felt::ZERO, Felt::ZERO, Felt::ZERO, Felt::new(value))
+}
+
+/// Builds the word `[a, b, c, d]`, with `a` at index 0 and `d` at index 3
+pub fn word_from_felts(a: Felt, b: Felt, c: Felt, d: Felt) -> Word {
+    Word::from([a, b, c, d])
+}
+
+/// Builds a word from 32 bytes, read as four little-endian 8-byte elements in index order
+///
+/// # Errors
+/// Returns an error if a chunk isn't a canonical field element, i.e. is at least the field
+/// modulus
+pub fn word_from_bytes(bytes: &[u8; 32]) -> Result<Word> {
+    Word::try_from(*bytes).context("Bytes don't encode a word of canonical field elements")
+}
+
+/// Asserts that exactly `amount` tokens of a faucet moved from one account to another
+///
+/// # Arguments
+/// * `client` - The Miden client instance, synced past the transfer
+/// * `from` - The ID of the sending account
+/// * `to` - The ID of the receiving account
+/// * `faucet_id` - The ID of the faucet that issued the tokens
+/// * `amount` - The amount of tokens transferred
+/// * `before_from` - The balance of `from` before the transfer
+/// * `before_to` - The balance of `to` before the transfer
+///
+/// # Errors
+/// Returns an error if an account isn't in the client store
+///
+/// # Panics
+/// Panics with both balances if either didn't change by exactly `amount`
+pub async fn assert_transfer(
+    client: &Client<FilesystemKeyStore<StdRng>>,
+    from: AccountId,
+    to: AccountId,
+    faucet_id: AccountId,
+    amount: u64,
+    before_from: u64,
+    before_to: u64,
+) -> Result<()> {
+    let after_from = fungible_balance(client, from, faucet_id).await?;
+    let after_to = fungible_balance(client, to, faucet_id).await?;
+
+    let moved = before_from.checked_sub(amount) == Some(after_from)
+        && before_to.checked_add(amount) == Some(after_to);
+    assert!(
+        moved,
+        "expected {amount} tokens of faucet {} to move from {} to {}: sender balance went from \
+         {before_from} to {after_from}, receiver balance went from {before_to} to {after_to}",
+        faucet_id.to_hex(),
+        from.to_hex(),
+        to.to_hex()
+    );
+
+    Ok(())
+}
+
+/// Version of the linked `cargo-miden` crate, e.g. "0.4.1"
+///
+/// Projects are compiled in-process by this crate rather than by an installed `cargo miden`
+/// subcommand, so this is the version that builds every package. It is read from the workspace
+/// lock file by the build script, and is "unknown" if the lock file couldn't be read.
+pub const CARGO_MIDEN_VERSION: &str = env!("CARGO_MIDEN_VERSION");
+
+/// Returns the version of the linked `cargo-miden` crate that compiles the projects
+pub fn cargo_miden_version() -> &'static str {
+    CARGO_MIDEN_VERSION
+}
+
+/// Parses the `major.minor.patch` prefix of a version, ignoring pre-release and build suffixes
+fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
+    let core = version.split(['-', '+']).next()?;
+    let mut parts = core.split('.').map(|part| part.parse::<u64>().ok());
+    let major = parts.next()??;
+    let minor = parts.next()??;
+    let patch = parts.next()??;
+    parts.next().is_none().then_some((major, minor, patch))
+}
+
+/// Checks that the linked `cargo-miden` is at least `minimum`
+///
+/// Builds check this when `BuildOptions::min_cargo_miden_version` is set, to catch an outdated
+/// toolchain, which produces packages the linked client can't execute.
+///
+/// # Arguments
+/// * `minimum` - The minimum version, e.g. "0.4.0"
+///
+/// # Returns
+/// The linked version
+///
+/// # Errors
+/// Returns `HelperError::OutdatedToolchain` if the linked version is older than `minimum`, or an
+/// error if `minimum` isn't a valid version or the linked version is unknown
+pub fn ensure_cargo_miden_version(minimum: &str) -> Result<&'static str> {
+    let required =
+        parse_version(minimum).context(format!("Invalid minimum version `{minimum}`"))?;
+    let found = cargo_miden_version();
+    let linked = parse_version(found).context(format!(
+        "Can't check that cargo-miden is at least {minimum}: linked version `{found}` isn't a \
+         valid version"
+    ))?;
+
+    if linked < required {
+        return Err(HelperError::OutdatedToolchain {
+            found: found.to_string(),
+            minimum: minimum.to_string(),
+        }
+        .into());
+    }
+
+    Ok(found)
+}
+
+/// Creates an account from a compiled package and deploys it on-chain
+///
+/// A new account only becomes known to the node with its first transaction, so an empty
+/// transaction is executed against the account and the client syncs until it is committed.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `package` - The compiled package containing the account component
+/// * `config` - Configuration for account creation
+///
+/// # Returns
+/// The deployed `Account`, as stored in the client after the deployment transaction
+///
+/// # Errors
+/// Returns an error if account creation fails, or the deployment transaction fails or isn't
+/// committed within `DEFAULT_MAX_BLOCKS` blocks
+pub async fn create_and_deploy_account(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    package: Arc<Package>,
+    config: AccountCreationConfig,
+) -> Result<Account> {
+    let account = create_account_from_package(client, package, config).await?;
+
+    let deploy_request = TransactionRequestBuilder::new()
+        .build()
+        .context("Failed to build deployment transaction request")?;
+    let tx_id = execute_and_submit(client, account.id(), deploy_request)
+        .await
+        .context("Failed to deploy account")?;
+
+    wait_for_transaction(client, tx_id, DEFAULT_MAX_BLOCKS)
+        .await
+        .context(format!(
+            "Deployment of account {} was not confirmed",
+            account.id().to_hex()
+        ))?;
+
+    let record = client
+        .get_account(account.id())
+        .await
+        .context("Failed to get account")?
+        .context("Account not found in client store")?;
+
+    Ok(record.account().clone())
+}
+
+/// Returns the map entries a transaction set in one storage map slot
+///
+/// For the counter, slot 0 reports the count key `counter_count_key()` with its new count.
+///
+/// # Arguments
+/// * `delta` - The account delta of the transaction
+/// * `slot` - The index of the map slot
+///
+/// # Returns
+/// The `(key, new_value)` pairs set in the slot, or an empty vec if the slot wasn't touched.
+/// Removed entries are reported with the empty word as their new value
+pub fn map_slot_changes(delta: &AccountDelta, slot: u8) -> Vec<(Word, Word)> {
+    delta
+        .storage()
+        .maps()
+        .get(&slot)
+        .map(|map_delta| {
+            map_delta
+                .entries()
+                .iter()
+                .map(|(key, value)| (Word::from(*key), *value))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// High-level description of a transaction, turned into a request by `build_request`
+#[derive(Debug, Clone)]
+pub enum Intent {
+    /// Emit a note from the executing account
+    PublishNote(Note),
+    /// Consume notes held in memory as unauthenticated input notes
+    ConsumeNotes(Vec<Note>),
+    /// Mint fungible tokens from `faucet` into a P2ID note for `target`
+    Mint {
+        faucet: AccountId,
+        target: AccountId,
+        amount: u64,
+        note_type: NoteType,
+    },
+    /// Send an asset from `from` to `to` through a P2ID note
+    Transfer {
+        from: AccountId,
+        to: AccountId,
+        asset: Asset,
+        note_type: NoteType,
+    },
+}
+
+/// Builds the transaction request fulfilling an intent
+///
+/// # Arguments
+/// * `intent` - The intent to fulfill
+/// * `rng` - The RNG drawing serial numbers of created notes, usually `client.rng()`
+///
+/// # Errors
+/// Returns an error if the request can't be built, e.g. for a mint amount above the maximum
+/// fungible asset amount
+pub fn build_request(intent: Intent, rng: &mut impl FeltRng) -> Result<TransactionRequest> {
+    let builder = TransactionRequestBuilder::new();
+
+    match intent {
+        Intent::PublishNote(note) => builder
+            .own_output_notes(vec![OutputNote::Full(note)])
+            .build()
+            .context("Failed to build publish note transaction request"),
+        Intent::ConsumeNotes(notes) => builder
+            .unauthenticated_input_notes(notes.into_iter().map(|note| (note, None)))
+            .build()
+            .context("Failed to build consume note transaction request"),
+        Intent::Mint {
+            faucet,
+            target,
+            amount,
+            note_type,
+        } => {
+            let asset =
+                FungibleAsset::new(faucet, amount).context("Failed to create fungible asset")?;
+            builder
+                .build_mint_fungible_asset(asset, target, note_type, rng)
+                .context("Failed to build mint transaction request")
+        }
+        Intent::Transfer {
+            from,
+            to,
+            asset,
+            note_type,
+        } => builder
+            .build_pay_to_id(
+                PaymentNoteDescription::new(vec![asset], from, to),
+                note_type,
+                rng,
+            )
+            .context("Failed to build transfer transaction request"),
+    }
+}
+
+/// Builds, executes and submits the transaction fulfilling an intent
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `account_id` - The ID of the executing account, i.e. the faucet of a `Mint` and the
+///   sender of a `Transfer`
+/// * `intent` - The intent to fulfill
+///
+/// # Returns
+/// The ID of the submitted transaction
+///
+/// # Errors
+/// Returns an error if the request can't be built, or the transaction fails to execute or
+/// submit
+pub async fn submit_intent(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    account_id: AccountId,
+    intent: Intent,
+) -> Result<TransactionId> {
+    let request = build_request(intent, client.rng())?;
+    execute_and_submit(client, account_id, request).await
+}
+
+/// Terminates the processes listening on a TCP port, e.g. a node left over by a crashed run
+///
+/// This is destructive and never called by the other helpers: it kills whatever process holds
+/// the port, not only Miden nodes. Processes are found with `lsof` and terminated with `kill`,
+/// so both must be installed.
+///
+/// # Arguments
+/// * `port` - The port the stale node's RPC server listens on
+///
+/// # Returns
+/// The number of processes that were terminated
+///
+/// # Errors
+/// Returns an error if `lsof` can't be run or a process can't be terminated
+pub fn kill_stale_nodes(port: u16) -> Result<usize> {
+    let output = std::process::Command::new("lsof")
+        .args(["-t", &format!("-iTCP:{port}"), "-sTCP:LISTEN"])
+        .output()
+        .context("Failed to run `lsof`")?;
+
+    // lsof exits with 1 and prints nothing when no process matches
+    let pids: BTreeSet<u32> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| line.trim().parse().ok())
+        .collect();
+
+    for pid in &pids {
+        let status = std::process::Command::new("kill")
+            .arg(pid.to_string())
+            .status()
+            .context("Failed to run `kill`")?;
+        if !status.success() {
+            bail!("Failed to terminate process {pid} listening on port {port}");
+        }
+    }
+
+    Ok(pids.len())
+}
+
+/// Criteria selecting the notes returned by `consumable_notes`
+///
+/// Named so it doesn't clash with the client store's `NoteFilter`. Unset criteria match every
+/// note.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ConsumableNoteFilter {
+    /// Only match notes holding a fungible asset of this faucet
+    pub faucet_id: Option<AccountId>,
+    /// Only match notes holding a fungible asset (of `faucet_id`, if set) of at least this amount
+    pub min_amount: Option<u64>,
+    /// Only match notes of this type
+    pub note_type: Option<NoteType>,
+}
+
+impl ConsumableNoteFilter {
+    /// Returns `true` if the note record satisfies every criterion
+    fn matches(&self, record: &InputNoteRecord) -> bool {
+        if let Some(note_type) = self.note_type {
+            if record.metadata().map(NoteMetadata::note_type) != Some(note_type) {
+                return false;
+            }
+        }
+
+        if self.faucet_id.is_none() && self.min_amount.is_none() {
+            return true;
+        }
+
+        record.assets().iter().any(|asset| match asset {
+            Asset::Fungible(fungible) => {
+                self.faucet_id
+                    .is_none_or(|faucet_id| fungible.faucet_id() == faucet_id)
+                    && self
+                        .min_amount
+                        .is_none_or(|min_amount| fungible.amount() >= min_amount)
+            }
+            Asset::NonFungible(_) => false,
+        })
+    }
+}
+
+/// Returns the notes the client knows that an account can consume, filtered by `filter`
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `account_id` - The ID of the account
+/// * `filter` - The criteria the notes must match
+///
+/// # Returns
+/// The matching note records, sorted by the block that included them. Notes not yet included
+/// in a block come last
+///
+/// # Errors
+/// Returns an error if the client store can't be queried
+pub async fn consumable_notes(
+    client: &Client<FilesystemKeyStore<StdRng>>,
+    account_id: AccountId,
+    filter: ConsumableNoteFilter,
+) -> Result<Vec<InputNoteRecord>> {
+    let consumable = client
+        .get_consumable_notes(Some(account_id))
+        .await
+        .context("Failed to get consumable notes")?;
+
+    let mut records: Vec<_> = consumable
+        .into_iter()
+        .map(|(record, _)| record)
+        .filter(|record| filter.matches(record))
+        .collect();
+    records.sort_by_key(|record| {
+        record
+            .inclusion_proof()
+            .map_or(u32::MAX, |proof| proof.location().block_num().as_u32())
+    });
+
+    Ok(records)
+}
+
+/// Temporary directory removed with its contents when dropped
+struct ScopedDir {
+    path: PathBuf,
+}
+
+impl Drop for ScopedDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
+
+/// Runs `f` with a fresh client whose keystore and store live in a unique temporary directory
+///
+/// The directory is removed once `f` completes, including when it returns an error or panics,
+/// so tests don't share state or leave files behind.
+///
+/// # Arguments
+/// * `f` - The test body, given the client and its keystore
+///
+/// # Returns
+/// The result of `f`
+///
+/// # Errors
+/// Returns an error if the client can't be set up, or the error returned by `f`
+pub async fn with_client<F, Fut, T>(f: F) -> Result<T>
+where
+    F: FnOnce(Client<FilesystemKeyStore<StdRng>>, Arc<FilesystemKeyStore<StdRng>>) -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let dir = ScopedDir {
+        path: std::env::temp_dir().join(format!("miden-client-{}", rand::random::<u64>())),
+    };
+    std::fs::create_dir_all(&dir.path).context(format!(
+        "Failed to create client directory {}",
+        dir.path.display()
+    ))?;
+
+    let ClientSetup {
+        client, keystore, ..
+    } = setup_client_at(
+        Endpoint::testnet(),
+        &dir.path.join("keystore"),
+        &dir.path.join("store.sqlite3"),
+        DebugMode::Enabled,
+        None,
+    )
+    .await?;
+
+    f(client, keystore).await
+}
+
+/// Encodes an account ID as the two note inputs `[suffix, prefix]` expected by P2ID notes
+pub fn account_id_to_note_inputs(account_id: AccountId) -> [Felt; 2] {
+    [account_id.suffix(), account_id.prefix().as_felt()]
+}
+
+/// Decodes an account ID from the first two note inputs, laid out as `[suffix, prefix]`
+///
+/// This is the inverse of `account_id_to_note_inputs`.
+///
+/// # Errors
+/// Returns `HelperError::InvalidAccountIdInputs` if there are fewer than two inputs or they
+/// don't form a valid account ID
+pub fn account_id_from_note_inputs(felts: &[Felt]) -> Result<AccountId> {
+    let [suffix, prefix] = felts
+        .get(..2)
+        .and_then(|felts| <[Felt; 2]>::try_from(felts).ok())
+        .ok_or_else(|| HelperError::InvalidAccountIdInputs {
+            reason: format!("expected at least 2 inputs, got {}", felts.len()),
+        })?;
+
+    AccountId::try_from([prefix, suffix]).map_err(|err| {
+        HelperError::InvalidAccountIdInputs {
+            reason: err.to_string(),
+        }
+        .into()
+    })
+}
+
+/// Timings of the increment cycles run by `bench_increments`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchReport {
+    /// Number of publish and consume cycles
+    pub cycles: usize,
+    /// Time taken by all cycles
+    pub total: Duration,
+    /// Average time per transaction, two transactions per cycle
+    pub per_transaction: Duration,
+    /// Fastest cycle
+    pub min: Duration,
+    /// Slowest cycle
+    pub max: Duration,
+}
+
+impl fmt::Display for BenchReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} increment cycles in {:?}", self.cycles, self.total)?;
+        writeln!(f, "  per transaction: {:?}", self.per_transaction)?;
+        write!(f, "  cycle min/max: {:?} / {:?}", self.min, self.max)
+    }
+}
+
+/// Number of times a benchmarked transaction is retried after a nonce conflict
+const BENCH_MAX_RETRIES: usize = 3;
+
+/// Executes and submits a transaction, syncing and retrying it after nonce conflicts
+///
+/// # Returns
+/// The ID of the submitted transaction and the time its successful attempt took to execute,
+/// prove and submit
+async fn timed_with_nonce_retries(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    account_id: AccountId,
+    intent: Intent,
+) -> Result<(TransactionId, Duration)> {
+    let mut attempt = 0;
+    loop {
+        let request = build_request(intent.clone(), client.rng())?;
+        let result: Result<(TransactionId, Duration)> = async {
+            let (tx_result, execute_time) = timed_execute(client, account_id, request).await?;
+            let (tx_id, submit_time) = timed_submit(client, tx_result).await?;
+            Ok((tx_id, execute_time + submit_time))
+        }
+        .await;
+
+        match result {
+            Err(err)
+                if attempt < BENCH_MAX_RETRIES
+                    && matches!(TxFailure::classify(&err), TxFailure::NonceMismatch(_)) =>
+            {
+                attempt += 1;
+                client.sync_state().await.context("Failed to sync state")?;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Measures the end-to-end throughput of the increment flow
+///
+/// Each cycle creates an increment note, publishes it from `sender_id` and consumes it with
+/// the counter, like the `increment_count` binary does. Transactions are timed with
+/// `timed_execute` and `timed_submit`, so a cycle's time covers executing, proving and
+/// submitting its two transactions but not the sync between them. Transactions failing on a
+/// nonce conflict are retried, and only their successful attempt is timed.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `counter_id` - The ID of the counter account
+/// * `note_package` - The compiled increment note package
+/// * `sender_id` - The ID of the account publishing the notes
+/// * `n` - The number of cycles to run
+///
+/// # Returns
+/// The total, per-transaction, fastest and slowest cycle times
+///
+/// # Errors
+/// Returns an error if `n` is zero, or a transaction fails for another reason than a nonce
+/// conflict or keeps conflicting after `BENCH_MAX_RETRIES` retries
+pub async fn bench_increments(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    counter_id: AccountId,
+    note_package: Arc<Package>,
+    sender_id: AccountId,
+    n: usize,
+) -> Result<BenchReport> {
+    if n == 0 {
+        bail!("At least one increment cycle is required");
+    }
+
+    let mut cycle_times = Vec::with_capacity(n);
+    for _ in 0..n {
+        let note = create_note_from_package(
+            client,
+            note_package.clone(),
+            sender_id,
+            NoteCreationConfig::default(),
+        )?;
+        let (_, publish_time) =
+            timed_with_nonce_retries(client, sender_id, Intent::PublishNote(note.clone()))
+                .await
+                .context("Failed to publish increment note")?;
+        client.sync_state().await.context("Failed to sync state")?;
+        let (_, consume_time) =
+            timed_with_nonce_retries(client, counter_id, Intent::ConsumeNotes(vec![note]))
+                .await
+                .context("Failed to consume increment note")?;
+
+        cycle_times.push(publish_time + consume_time);
+    }
+
+    let total: Duration = cycle_times.iter().sum();
+    Ok(BenchReport {
+        cycles: n,
+        total,
+        // Two transactions per cycle
+        per_transaction: total.div_f64(2.0 * n as f64),
+        min: cycle_times.iter().copied().min().unwrap_or_default(),
+        max: cycle_times.iter().copied().max().unwrap_or_default(),
+    })
+}
+
+/// Asserts that a fungible faucet refuses to mint beyond its maximum supply
+///
+/// The faucet first mints its remaining supply, which must succeed, then tries to mint one more
+/// token, which must be rejected. The minted notes target the faucet itself and are never
+/// consumed.
+///
+/// # Arguments
+/// * `client` - The Miden client instance, which must hold the faucet's key
+/// * `faucet_id` - The ID of the faucet account
+/// * `max_supply` - The maximum supply the faucet was created with
+///
+/// # Errors
+/// Returns an error if the issuance can't be read, exceeds `max_supply` already, or minting the
+/// remaining supply fails
+///
+/// # Panics
+/// Panics with the observed issuance if minting beyond `max_supply` succeeds, or with the error
+/// if it fails for any reason other than the faucet rejecting it for exceeding its max supply
+pub async fn assert_max_supply_enforced(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    faucet_id: AccountId,
+    max_supply: u64,
+) -> Result<()> {
+    let issuance = faucet_total_issuance(client, faucet_id).await?;
+    let remaining = max_supply.checked_sub(issuance).context(format!(
+        "Faucet issuance {issuance} already exceeds max supply {max_supply}"
+    ))?;
+
+    if remaining > 0 {
+        submit_intent(
+            client,
+            faucet_id,
+            Intent::Mint {
+                faucet: faucet_id,
+                target: faucet_id,
+                amount: remaining,
+                note_type: NoteType::Public,
+            },
+        )
+        .await
+        .context("Failed to mint the remaining supply")?;
+    }
+
+    let over_mint = Intent::Mint {
+        faucet: faucet_id,
+        target: faucet_id,
+        amount: 1,
+        note_type: NoteType::Public,
+    };
+    let max_supply_exceeded =
+        ERR_FUNGIBLE_ASSET_DISTRIBUTE_WOULD_CAUSE_MAX_SUPPLY_TO_BE_EXCEEDED.code();
+    match submit_intent(client, faucet_id, over_mint).await {
+        Ok(_) => {
+            let issuance = faucet_total_issuance(client, faucet_id).await?;
+            panic!(
+                "faucet {} minted beyond its max supply of {max_supply}, issuance is now \
+                 {issuance}",
+                faucet_id.to_hex()
+            );
+        }
+        Err(err) if failed_assertion_code(&err) == Some(max_supply_exceeded) => Ok(()),
+        Err(err) => panic!(
+            "minting beyond the max supply of faucet {} failed for another reason: {err:#}",
+            faucet_id.to_hex()
+        ),
+    }
+}
+
+/// Builds a contract together with the shared library it links against
+///
+/// The library is built first, so its own compilation errors are reported separately from
+/// those of the contract. The contract must declare the library under
+/// `[package.metadata.miden.dependencies]`, like the increment note does for the counter
+/// account. Calls to procedures the library doesn't export fail the contract's build.
+///
+/// # Arguments
+/// * `main_dir` - Path to the directory containing the contract's Cargo.toml
+/// * `lib_dir` - Path to the directory containing the library's Cargo.toml
+/// * `release` - Whether to build in release mode
+///
+/// # Returns
+/// The compiled contract `Package`
+///
+/// # Errors
+/// Returns `BuildError::Compilation` if either build fails, or an error if the contract doesn't
+/// link the library built from `lib_dir`
+pub fn build_with_shared_lib(main_dir: &Path, lib_dir: &Path, release: bool) -> Result<Package> {
+    let library = build_project_in_dir(lib_dir, release).context(format!(
+        "Failed to build shared library {}",
+        lib_dir.display()
+    ))?;
+    ensure_package_kind(&library, PackageKind::Library)?;
+    let library_digest = *library.unwrap_library().digest();
+
+    let package = build_project_in_dir(main_dir, release)
+        .context(format!("Failed to build contract {}", main_dir.display()))?;
+
+    let links_library = package
+        .manifest
+        .dependencies()
+        .any(|dependency| dependency.digest == library_digest);
+    if !links_library {
+        bail!(
+            "Contract {} doesn't link the library built from {}; declare it under \
+             [package.metadata.miden.dependencies]",
+            main_dir.display(),
+            lib_dir.display()
+        );
+    }
+
+    Ok(package)
+}
+
+/// Returns the number of VM cycles a transaction took to execute
+///
+/// This sums the prologue, note processing, transaction script and epilogue cycles reported
+/// by the executed transaction's measurements. The per-phase counts are available through
+/// `tx_result.executed_transaction().measurements()`.
+pub fn transaction_cycles(tx_result: &TransactionResult) -> u64 {
+    tx_result
+        .executed_transaction()
+        .measurements()
+        .total_cycles() as u64
+}
+
+/// Asserts that a transaction executed within a cycle budget
+///
+/// # Panics
+/// Panics with the cycle count if the transaction took more than `limit` cycles
+pub fn assert_cycles_under(tx_result: &TransactionResult, limit: u64) {
+    let cycles = transaction_cycles(tx_result);
+    assert!(
+        cycles <= limit,
+        "transaction {} took {cycles} cycles, over the budget of {limit}",
+        tx_result.executed_transaction().id().to_hex()
+    );
+}
+
+/// Consumes a P2IDR note back into its sender once its recall height is reached
+///
+/// The client should be synced first, as recallability is checked against its sync height.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `original_note` - The P2IDR note to recall
+/// * `sender_id` - The ID of the account that sent the note
+///
+/// # Returns
+/// The ID of the submitted recall transaction
+///
+/// # Errors
+/// Returns an error if `sender_id` didn't send the note, the recall height isn't reached yet,
+/// or the transaction fails to execute or submit
+pub async fn recall_note(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    original_note: &Note,
+    sender_id: AccountId,
+) -> Result<TransactionId> {
+    if original_note.metadata().sender() != sender_id {
+        bail!(
+            "Note {} was sent by {}, not {}",
+            original_note.id().to_hex(),
+            original_note.metadata().sender().to_hex(),
+            sender_id.to_hex()
+        );
+    }
+
+    let remaining = blocks_until_recallable(client, original_note).await?;
+    if remaining > 0 {
+        bail!(
+            "Note {} can only be recalled in {remaining} blocks",
+            original_note.id().to_hex()
+        );
+    }
+
+    consume_notes(client, sender_id, std::slice::from_ref(original_note))
+        .await
+        .context("Failed to recall note")
+}
+
+/// Checks that a note's inputs fit within the protocol's per-note limit
+///
+/// # Errors
+/// Returns `NoteError::TooManyInputs` if there are more than `MAX_INPUTS_PER_NOTE` inputs
+pub fn validate_note_inputs(inputs: &[Felt]) -> Result<()> {
+    if inputs.len() > MAX_INPUTS_PER_NOTE {
+        return Err(NoteError::TooManyInputs {
+            count: inputs.len(),
+            max: MAX_INPUTS_PER_NOTE,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Creates storage slots from a TOML or JSON description
+///
+/// Files with a `.json` extension are parsed as JSON, any other file as TOML. The file lists
+/// the slots in order, each either a value slot or a map slot:
+///
+/// ```toml
+/// [[slots]]
+/// kind = "value"
+/// value = [0, 0, 0, 1]
+///
+/// [[slots]]
+/// kind = "map"
+/// entries = [{ key = [0, 0, 0, 1], value = [0, 0, 0, 0] }]
+/// ```
+///
+/// Words are written as four integers, in the same order as `Word::from([Felt; 4])`.
+///
+/// # Arguments
+/// * `path` - The storage description file
+///
+/// # Returns
+/// The storage slots, ready for `AccountCreationConfig::storage_slots`
+///
+/// # Errors
+/// Returns `HelperError::InvalidStorageFile` if the file doesn't follow the format above or a
+/// word element isn't a canonical field element, or an error if the file can't be read
+pub fn storage_slots_from_file(path: &Path) -> Result<Vec<StorageSlot>> {
+    let contents = std::fs::read_to_string(path)
+        .context(format!("Failed to read storage file {}", path.display()))?;
+    let invalid = |reason: String| -> anyhow::Error {
+        HelperError::InvalidStorageFile {
+            path: path.to_path_buf(),
+            reason,
+        }
+        .into()
+    };
+
+    let document: serde_json::Value = if path.extension().is_some_and(|ext| ext == "json") {
+        serde_json::from_str(&contents).map_err(|err| invalid(err.to_string()))?
+    } else {
+        let document: toml::Value =
+            toml::from_str(&contents).map_err(|err| invalid(err.to_string()))?;
+        serde_json::to_value(document).map_err(|err| invalid(err.to_string()))?
+    };
+
+    let slots = document
+        .get("slots")
+        .and_then(serde_json::Value::as_array)
+        .ok_or_else(|| invalid("missing `slots` array".to_string()))?;
+
+    slots
+        .iter()
+        .enumerate()
+        .map(|(index, slot)| {
+            storage_slot_from_value(slot)
+                .map_err(|reason| invalid(format!("slot {index}: {reason}")))
+        })
+        .collect()
+}
+
+/// Parses one slot of a storage description file
+fn storage_slot_from_value(slot: &serde_json::Value) -> Result<StorageSlot, String> {
+    match slot.get("kind").and_then(serde_json::Value::as_str) {
+        Some("value") => {
+            let value = slot.get("value").ok_or("missing `value`")?;
+            Ok(StorageSlot::Value(word_from_value(value)?))
+        }
+        Some("map") => {
+            let entries = match slot.get("entries") {
+                Some(entries) => entries
+                    .as_array()
+                    .ok_or("`entries` must be an array")?
+                    .clone(),
+                None => Vec::new(),
+            };
+            let entries = entries
+                .iter()
+                .map(|entry| {
+                    let key = entry.get("key").ok_or("map entry is missing `key`")?;
+                    let value = entry.get("value").ok_or("map entry is missing `value`")?;
+                    Ok((word_from_value(key)?, word_from_value(value)?))
+                })
+                .collect::<Result<Vec<_>, String>>()?;
+            let map = StorageMap::with_entries(entries).map_err(|err| err.to_string())?;
+            Ok(StorageSlot::Map(map))
+        }
+        Some(kind) => Err(format!(
+            "unknown slot kind `{kind}`, expected `value` or `map`"
+        )),
+        None => Err("missing `kind`".to_string()),
+    }
+}
+
+/// Parses a word written as an array of four integers
+fn word_from_value(value: &serde_json::Value) -> Result<Word, String> {
+    let elements = value
+        .as_array()
+        .filter(|elements| elements.len() == 4)
+        .ok_or_else(|| format!("expected a word of 4 integers, got {value}"))?;
+
+    let mut felts = [Felt::ZERO; 4];
+    for (felt, element) in felts.iter_mut().zip(elements) {
+        let element = element
+            .as_u64()
+            .ok_or_else(|| format!("expected an unsigned integer, got {element}"))?;
+        *felt = Felt::try_from(element)
+            .map_err(|_| format!("{element} is not a canonical field element"))?;
+    }
+
+    Ok(Word::from(felts))
+}
+
+/// Checks that a freshly built package is functionally identical to a committed artifact
+///
+/// # Arguments
+/// * `package` - The freshly built package
+/// * `artifact_path` - The artifact saved with `save_package`
+///
+/// # Errors
+/// Returns `HelperError::ArtifactMismatch` with the diverging procedures if the MAST roots
+/// differ, or an error if the artifact can't be loaded
+pub fn assert_package_matches_artifact(package: &Package, artifact_path: &Path) -> Result<()> {
+    let artifact = load_package(artifact_path)?;
+
+    let diff = package_diff(&artifact, package);
+    if !diff.is_empty() {
+        return Err(HelperError::ArtifactMismatch {
+            path: artifact_path.to_path_buf(),
+            diff,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Closure signing the elements of a transaction summary
+pub type SignerFn = dyn Fn(&[Felt]) -> Signature + Send + Sync;
+
+tokio::task_local! {
+    /// Signer of the transaction being executed by `submit_with_external_signer`
+    static EXTERNAL_SIGNER: Arc<SignerFn>;
+}
+
+/// Transaction authenticator delegating signing to an externally managed key
+///
+/// Signing requests are forwarded to the signer passed to `submit_with_external_signer`, e.g. to
+/// an HSM or remote signer, and no key is ever written to disk. Signatures are verified against
+/// the account's public key before being handed to the transaction.
+pub struct ExternalSigner;
+
+impl TransactionAuthenticator for ExternalSigner {
+    async fn get_signature(
+        &self,
+        pub_key: Word,
+        signing_inputs: &SigningInputs,
+    ) -> Result<Signature, AuthenticationError> {
+        let signer = EXTERNAL_SIGNER.try_with(Arc::clone).map_err(|_| {
+            AuthenticationError::other(
+                "no external signer installed, execute the transaction with \
+                 `submit_with_external_signer`",
+            )
+        })?;
+        let signature = signer(&signing_inputs.to_elements());
+
+        let message = signing_inputs.to_commitment();
+        let valid = match &signature {
+            Signature::RpoFalcon512(signature) => {
+                PublicKey::new(pub_key).verify(message, signature)
+            }
+        };
+        if !valid {
+            return Err(AuthenticationError::other(format!(
+                "external signer returned an invalid signature for public key {}",
+                pub_key.to_hex()
+            )));
+        }
+
+        Ok(signature)
+    }
+}
+
+/// Initializes a client that signs transactions with an external signer instead of a keystore
+///
+/// The signer itself is passed to each `submit_with_external_signer` call.
+///
+/// # Arguments
+/// * `endpoint` - The RPC endpoint of the node
+/// * `store_path` - Path of the SQLite store
+/// * `debug_mode` - Whether the client executes transactions in debug mode
+///
+/// # Errors
+/// Returns `SetupError::Unreachable` if the node can't be reached, or an error if the store
+/// can't be opened or client building fails
+pub async fn setup_client_with_signer(
+    endpoint: Endpoint,
+    store_path: &Path,
+    debug_mode: DebugMode,
+) -> Result<Client<ExternalSigner>> {
+    let rpc_api = Arc::new(TonicRpcClient::new(&endpoint, RPC_TIMEOUT_MS));
+    build_client(
+        &endpoint,
+        rpc_api,
+        store_path,
+        Arc::new(ExternalSigner),
+        debug_mode,
+        None,
+    )
+    .await
+}
+
+/// Executes and submits a transaction signed by an external signer
+///
+/// # Arguments
+/// * `client` - A client created with `setup_client_with_signer`
+/// * `account_id` - The ID of the account executing the transaction
+/// * `request` - The transaction request
+/// * `signer` - Closure signing the elements of the transaction summary
+///
+/// # Returns
+/// The ID of the submitted transaction
+///
+/// # Errors
+/// Returns an error if the signer produces an invalid signature, or the transaction fails to
+/// execute or submit
+pub async fn submit_with_external_signer(
+    client: &mut Client<ExternalSigner>,
+    account_id: AccountId,
+    request: TransactionRequest,
+    signer: impl Fn(&[Felt]) -> Signature + Send + Sync + 'static,
+) -> Result<TransactionId> {
+    let tx_result = EXTERNAL_SIGNER
+        .scope(
+            Arc::new(signer),
+            client.new_transaction(account_id, request),
+        )
+        .await
+        .context("Failed to create externally signed transaction")?;
+
+    let tx_id = tx_result.executed_transaction().id();
+
+    client
+        .submit_transaction(tx_result)
+        .await
+        .context("Failed to submit externally signed transaction")?;
+
+    Ok(tx_id)
+}
+
+/// Returns the note tags the client fetches notes for when syncing
+///
+/// A note is only discovered by `sync_state` if its tag is tracked, so this is the first thing
+/// to check when a published note never shows up.
+///
+/// # Returns
+/// The tracked tags, sorted and without duplicates
+///
+/// # Errors
+/// Returns an error if the client store can't be queried
+pub async fn tracked_tags(client: &Client<FilesystemKeyStore<StdRng>>) -> Result<Vec<NoteTag>> {
+    let mut tags: Vec<NoteTag> = client
+        .get_note_tags()
+        .await
+        .context("Failed to get tracked note tags")?
+        .into_iter()
+        .map(|record| record.tag)
+        .collect();
+    tags.sort_by_key(|tag| u32::from(*tag));
+    tags.dedup();
+
+    Ok(tags)
+}
+
+/// Starts tracking a note tag, so notes carrying it are fetched on the next sync
+///
+/// Tracking a tag that is already tracked is a no-op.
+///
+/// # Errors
+/// Returns an error if the client store can't be queried or updated
+pub async fn add_tracked_tag(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    tag: NoteTag,
+) -> Result<()> {
+    if tracked_tags(client).await?.contains(&tag) {
+        return Ok(());
+    }
+
+    client
+        .add_note_tag(tag)
+        .await
+        .context("Failed to track note tag")
+}
+
+/// Creates a note from a compiled package that is consumable from block `unlock_block` onward
+///
+/// The execution hint is set to `after_block(unlock_block)` and the unlock height is prepended
+/// to `config.inputs`. Since execution hints aren't enforced by the transaction kernel, the
+/// note script must compare that input against the current block height for consumption
+/// before `unlock_block` to fail.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `package` - The compiled package containing the note script
+/// * `sender_id` - The ID of the account sending the note
+/// * `unlock_block` - The first block in which the note may be consumed
+/// * `config` - Configuration for note creation, whose execution hint is replaced
+///
+/// # Returns
+/// The created `Note`
+///
+/// # Errors
+/// Returns an error if `unlock_block` isn't after the client's sync height, or if note
+/// creation fails
+pub async fn create_timelocked_note(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    package: Arc<Package>,
+    sender_id: AccountId,
+    unlock_block: u32,
+    mut config: NoteCreationConfig,
+) -> Result<Note> {
+    let sync_height = client
+        .get_sync_height()
+        .await
+        .context("Failed to get sync height")?
+        .as_u32();
+    if unlock_block <= sync_height {
+        bail!("Unlock block {unlock_block} is not after the current sync height {sync_height}");
+    }
+
+    config.execution_hint = NoteExecutionHint::after_block(unlock_block.into())
+        .context("Failed to create after-block execution hint")?;
+    config.inputs.insert(0, Felt::from(unlock_block));
+
+    create_note_from_package(client, package, sender_id, config)
+}
+
+/// Serializes a transaction request, e.g. to replay a failing transaction locally
+pub fn export_request(request: &TransactionRequest) -> Vec<u8> {
+    request.to_bytes()
+}
+
+/// Deserializes a transaction request written with `export_request`
+///
+/// # Errors
+/// Returns `HelperError::InvalidTransactionRequest` if the bytes can't be decoded
+pub fn import_request(bytes: &[u8]) -> Result<TransactionRequest> {
+    TransactionRequest::read_from_bytes(bytes).map_err(|err| {
+        HelperError::InvalidTransactionRequest {
+            reason: err.to_string(),
+        }
+        .into()
+    })
+}
+
+/// Storage slot of the public key of accounts built with `AuthRpoFalcon512` as their auth
+/// component and no component storage before it, e.g. the wallets of
+/// `create_basic_wallet_account`
+pub const RPO_FALCON_512_PUBLIC_KEY_SLOT: u8 = 0;
+
+/// Checks that the keystore holds a key producing valid signatures for an account
+///
+/// The public key is read from the given storage slot of the account's `AuthRpoFalcon512`
+/// component, and a dummy message is signed with the matching secret key and verified against
+/// it. The slot depends on the order of the account's components and the storage of those
+/// placed before the auth component.
+///
+/// # Arguments
+/// * `keystore` - The keystore expected to hold the account's key
+/// * `account` - The account, authenticated with `AuthRpoFalcon512`
+/// * `public_key_slot` - The storage slot holding the account's public key, e.g.
+///   `RPO_FALCON_512_PUBLIC_KEY_SLOT`
+///
+/// # Errors
+/// Returns `HelperError::SigningKeyMismatch` describing the mismatch if the keystore has no
+/// key for the account's public key or the key's signature doesn't verify, or an error if the
+/// keystore or account storage can't be read
+pub fn verify_signing_capability(
+    keystore: &FilesystemKeyStore<StdRng>,
+    account: &Account,
+    public_key_slot: u8,
+) -> Result<()> {
+    let mismatch = |reason: String| -> anyhow::Error {
+        HelperError::SigningKeyMismatch {
+            account_id: account.id(),
+            reason,
+        }
+        .into()
+    };
+
+    let pub_key = account
+        .storage()
+        .get_item(public_key_slot)
+        .context(format!(
+            "Failed to read account public key from storage slot {public_key_slot}"
+        ))?;
+
+    let secret_key = match keystore
+        .get_key(pub_key)
+        .context("Failed to read keystore")?
+    {
+        Some(AuthSecretKey::RpoFalcon512(secret_key)) => secret_key,
+        None => {
+            return Err(mismatch(format!(
+                "no key stored for public key {}",
+                pub_key.to_hex()
+            )))
+        }
+    };
+
+    if Word::from(secret_key.public_key()) != pub_key {
+        return Err(mismatch(format!(
+            "stored key doesn't match public key {}",
+            pub_key.to_hex()
+        )));
+    }
+
+    let message = word_from_u64(1);
+    let signature = secret_key.sign(message);
+    if !PublicKey::new(pub_key).verify(message, &signature) {
+        return Err(mismatch(
+            "signature of the stored key doesn't verify".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Assets held in an account's vault, displayed grouped by faucet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountAssets(pub Vec<Asset>);
+
+impl fmt::Display for AccountAssets {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return writeln!(f, "Empty vault");
+        }
+
+        let mut by_faucet: BTreeMap<String, Vec<&Asset>> = BTreeMap::new();
+        for asset in &self.0 {
+            let faucet = match asset {
+                Asset::Fungible(fungible) => fungible.faucet_id().to_hex(),
+                Asset::NonFungible(_) => asset.faucet_id_prefix().to_hex(),
+            };
+            by_faucet.entry(faucet).or_default().push(asset);
+        }
+
+        for (faucet, assets) in by_faucet {
+            writeln!(f, "Faucet {faucet}")?;
+            for asset in assets {
+                match asset {
+                    Asset::Fungible(fungible) => writeln!(f, "  fungible: {}", fungible.amount())?,
+                    Asset::NonFungible(_) => {
+                        writeln!(f, "  non-fungible: {}", Word::from(*asset).to_hex())?
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns every asset, fungible and non-fungible, held in an account's vault
+///
+/// # Arguments
+/// * `client` - The Miden client tracking the account
+/// * `account_id` - The account whose vault to read
+///
+/// # Returns
+/// The vault's assets, empty for an empty vault. Print them with `{}` to group them by faucet.
+pub async fn account_assets(
+    client: &Client<FilesystemKeyStore<StdRng>>,
+    account_id: AccountId,
+) -> Result<AccountAssets> {
+    let record = client
+        .get_account(account_id)
+        .await
+        .context("Failed to get account")?
+        .context("Account not found in client store")?;
+
+    Ok(AccountAssets(record.account().vault().assets().collect()))
+}
+
+/// Returns the size of a client's SQLite store on disk, including its write-ahead log
+///
+/// # Arguments
+/// * `params` - The parameters the client was set up with, i.e. `ClientSetup::params`
+///
+/// # Errors
+/// Returns an error if the store file doesn't exist, e.g. for an in-memory store, or its
+/// metadata can't be read
+pub fn store_size_bytes(params: &SetupParams) -> std::io::Result<u64> {
+    let store_path = params.store_path.as_path();
+    let mut size = std::fs::metadata(store_path)?.len();
+
+    match std::fs::metadata(sqlite_sidecar_path(store_path, "-wal")) {
+        Ok(metadata) => size += metadata.len(),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err),
+    }
+
+    Ok(size)
+}
+
+/// Reclaims the space of deleted rows in a client's SQLite store by running `VACUUM`
+///
+/// The client doesn't expose its database connection, so the store is compacted through a
+/// separate connection. `VACUUM` fails while another connection is writing, so call this
+/// between transactions, when the client is idle.
+///
+/// # Arguments
+/// * `setup` - The client whose store to compact
+///
+/// # Returns
+/// The number of bytes reclaimed
+///
+/// # Errors
+/// Returns an error if the store doesn't exist or can't be opened, or if `VACUUM` fails
+pub fn compact_store(setup: &ClientSetup) -> Result<u64> {
+    let store_path = setup.params.store_path.as_path();
+    let size_before = store_size_bytes(&setup.params)
+        .context(format!("Failed to read size of {}", store_path.display()))?;
+
+    let connection = rusqlite::Connection::open_with_flags(
+        store_path,
+        rusqlite::OpenFlags::SQLITE_OPEN_READ_WRITE,
+    )
+    .context(format!("Failed to open {}", store_path.display()))?;
+    connection
+        .execute_batch("PRAGMA wal_checkpoint(TRUNCATE); VACUUM;")
+        .context(format!("Failed to compact {}", store_path.display()))?;
+    drop(connection);
+
+    let size_after = store_size_bytes(&setup.params)
+        .context(format!("Failed to read size of {}", store_path.display()))?;
+    Ok(size_before.saturating_sub(size_after))
+}
+
+/// Asserts that the client has settled, i.e. that syncing again reports no changes
+///
+/// The client is first synced until it has caught up with the chain tip, then synced once
+/// more. That second sync must not report new blocks, notes, account updates or committed
+/// transactions. Use it as a barrier between test phases, so a phase doesn't start before the
+/// effects of the previous one reached the client.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+///
+/// # Errors
+/// Returns an error if a sync fails
+///
+/// # Panics
+/// Panics with the second sync's summary if it reported any change
+pub async fn assert_sync_stable(client: &mut Client<FilesystemKeyStore<StdRng>>) -> Result<()> {
+    let settled = sync_with_progress(client, |_| {}).await?;
+    let next = client.sync_state().await.context("Failed to sync state")?;
+
+    let unchanged = next.block_num == settled.block_num
+        && next.new_public_notes.is_empty()
+        && next.committed_notes.is_empty()
+        && next.consumed_notes.is_empty()
+        && next.updated_accounts.is_empty()
+        && next.locked_accounts.is_empty()
+        && next.committed_transactions.is_empty();
+    assert!(
+        unchanged,
+        "Sync after block {} still reported changes: {next:#?}",
+        settled.block_num.as_u32()
+    );
+
+    Ok(())
+}
+
+/// Creates a fungible faucet and a wallet funded with `FIXTURE_MINT_AMOUNT` of its tokens
+///
+/// This is the `TestFixture` setup for scripts that already own a client. Use
+/// `quick_start_with_amount` to fund the wallet with a different amount.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `keystore` - The keystore holding the keys of the new accounts
+///
+/// # Returns
+/// The `(faucet, wallet)` pair in their state after the wallet was funded
+///
+/// # Errors
+/// Returns an error if account creation, minting, consuming or syncing fails
+pub async fn quick_start(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    keystore: Arc<FilesystemKeyStore<StdRng>>,
+) -> Result<(Account, Account)> {
+    quick_start_with_amount(client, keystore, FIXTURE_MINT_AMOUNT).await
+}
+
+/// Creates a fungible faucet and a wallet funded with `amount` of its tokens
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `keystore` - The keystore holding the keys of the new accounts
+/// * `amount` - The amount of tokens minted into the wallet
+///
+/// # Returns
+/// The `(faucet, wallet)` pair in their state after the wallet was funded
+///
+/// # Errors
+/// Returns an error if account creation, minting, consuming or syncing fails
+pub async fn quick_start_with_amount(
+    client: &mut Client<FilesystemKeyStore<StdRng>>,
+    keystore: Arc<FilesystemKeyStore<StdRng>>,
+    amount: u64,
+) -> Result<(Account, Account)> {
+    client.sync_state().await.context("Failed to sync state")?;
+
+    let faucet = create_fixture_faucet(client, keystore.clone()).await?;
+    let wallet = create_funded_wallet(client, keystore, faucet.id(), amount).await?;
+
+    client.sync_state().await.context("Failed to sync state")?;
+
+    let faucet = client
+        .get_account(faucet.id())
+        .await
+        .context("Failed to get faucet account")?
+        .context("Faucet account not found in client store")?;
+    let wallet = client
+        .get_account(wallet.id())
+        .await
+        .context("Failed to get wallet account")?
+        .context("Wallet account not found in client store")?;
+
+    Ok((faucet.account().clone(), wallet.account().clone()))
+}
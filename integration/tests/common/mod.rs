@@ -0,0 +1,149 @@
+//! Setup shared by the tests consuming an increment note on a mock counter account
+
+// Each test crate only uses some of these helpers
+#![allow(dead_code)]
+
+use integration::helpers::{
+    build_project_in_dir_async, counter_count_key, create_testing_account_from_package,
+    word_from_u64, AccountCreationConfig,
+};
+
+use miden_client::{
+    account::{Account, StorageMap, StorageSlot},
+    builder::ClientBuilder,
+    keystore::FilesystemKeyStore,
+    note::Note,
+    testing::mock::MockRpcApi,
+    Client, DebugMode, Word,
+};
+use miden_mast_package::Package;
+use miden_testing::{Auth, MockChain, MockChainBuilder, TransactionContextBuilder};
+use rand::rngs::StdRng;
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+/// A mock chain with a note sender wallet and a counter account holding a count of 0
+pub struct CounterSetup {
+    pub builder: MockChainBuilder,
+    pub sender: Account,
+    pub counter_account: Account,
+    pub contract_package: Arc<Package>,
+    pub note_package: Arc<Package>,
+}
+
+/// Builds the counter and increment note contracts and creates the counter account
+///
+/// The counter account isn't added to the builder yet, so tests can add it along with their
+/// notes.
+pub async fn counter_setup() -> anyhow::Result<CounterSetup> {
+    let mut builder = MockChain::builder();
+
+    // Create note sender account
+    let sender = builder.add_existing_wallet(Auth::BasicAuth)?;
+
+    // Build contracts
+    let contract_package = Arc::new(
+        build_project_in_dir_async(Path::new("../contracts/counter-account"), true).await?,
+    );
+    let note_package =
+        Arc::new(build_project_in_dir_async(Path::new("../contracts/increment-note"), true).await?);
+
+    // Create the counter account with initial storage and no-auth auth component
+    let counter_cfg = AccountCreationConfig {
+        storage_slots: vec![StorageSlot::Map(StorageMap::with_entries([(
+            counter_count_key(),
+            word_from_u64(0),
+        )])?)],
+        ..Default::default()
+    };
+    let counter_account =
+        create_testing_account_from_package(contract_package.clone(), counter_cfg).await?;
+
+    Ok(CounterSetup {
+        builder,
+        sender,
+        counter_account,
+        contract_package,
+        note_package,
+    })
+}
+
+/// Consumes `note` as an unauthenticated input note on the counter account
+///
+/// # Returns
+/// The count of the counter account after the transaction
+pub async fn consume_on_counter(setup: CounterSetup, note: Note) -> anyhow::Result<Word> {
+    let updated_counter_account = execute_on_counter(setup, note).await?;
+    Ok(updated_counter_account
+        .storage()
+        .get_map_item(0, counter_count_key())?)
+}
+
+/// Consumes `note` as an unauthenticated input note on the counter account
+///
+/// # Returns
+/// The counter account after the transaction
+pub async fn execute_on_counter(setup: CounterSetup, note: Note) -> anyhow::Result<Account> {
+    let CounterSetup {
+        mut builder,
+        counter_account,
+        ..
+    } = setup;
+
+    builder.add_account(counter_account.clone())?;
+    let mut mock_chain = builder.build()?;
+
+    let tx_inputs =
+        mock_chain.get_transaction_inputs(counter_account.clone(), None, &[], &[note])?;
+    let tx_context = TransactionContextBuilder::new(counter_account)
+        .account_seed(None)
+        .tx_inputs(tx_inputs)
+        .build()?;
+    let executed_transaction = tx_context.execute().await?;
+
+    Ok(mock_chain.add_pending_executed_transaction(&executed_transaction)?)
+}
+
+/// Client backed by a mock node, with its keystore and store in a unique temporary directory
+pub struct MockClient {
+    pub client: Client<FilesystemKeyStore<StdRng>>,
+    pub keystore: Arc<FilesystemKeyStore<StdRng>>,
+    // Declared last so the directory is removed after the client closed its store
+    _dir: ScopedDir,
+}
+
+/// Temporary directory removed with its contents when dropped
+struct ScopedDir(PathBuf);
+
+impl Drop for ScopedDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+/// Creates a client connected to `rpc`, so several clients can share the same mock node
+pub async fn mock_client(rpc: Arc<MockRpcApi>) -> anyhow::Result<MockClient> {
+    let dir = ScopedDir(
+        std::env::temp_dir().join(format!("miden-mock-client-{}", rand::random::<u64>())),
+    );
+    std::fs::create_dir_all(&dir.0)?;
+
+    let keystore = Arc::new(FilesystemKeyStore::new(dir.0.join("keystore"))?);
+    let store_path = dir.0.join("store.sqlite3");
+    let mut client = ClientBuilder::new()
+        .rpc(rpc)
+        .sqlite_store(store_path.to_str().expect("temporary path is valid UTF-8"))
+        .authenticator(keystore.clone())
+        .in_debug_mode(DebugMode::Enabled)
+        .build()
+        .await?;
+    client.sync_state().await?;
+
+    Ok(MockClient {
+        client,
+        keystore,
+        _dir: dir,
+    })
+}
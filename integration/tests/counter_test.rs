@@ -1,89 +1,36 @@
+mod common;
+
+use common::{counter_setup, execute_on_counter};
 use integration::helpers::{
-    build_project_in_dir, create_testing_account_from_package, create_testing_note_from_package,
-    AccountCreationConfig, NoteCreationConfig,
+    account_state_diff, create_testing_note_from_package, read_typed, word_from_u64,
+    NoteCreationConfig, StorageSchema,
 };
 
-use miden_client::{account::StorageMap, transaction::OutputNote, Felt, Word};
-use miden_testing::{Auth, MockChain, TransactionContextBuilder};
-use std::{path::Path, sync::Arc};
-
 #[tokio::test]
 async fn counter_test() -> anyhow::Result<()> {
     // Test that after executing the increment note, the counter value is incremented by 1
-    let mut builder = MockChain::builder();
-
-    // Crate note sender account
-    let sender = builder.add_existing_wallet(Auth::BasicAuth)?;
-
-    // Build contracts
-    let contract_package = Arc::new(build_project_in_dir(
-        Path::new("../contracts/counter-account"),
-        true,
-    )?);
-    let note_package = Arc::new(build_project_in_dir(
-        Path::new("../contracts/increment-note"),
-        true,
-    )?);
-
-    // Create the counter account with initial storage and no-auth auth component
-    let count_storage_key = Word::from([Felt::new(0), Felt::new(0), Felt::new(0), Felt::new(1)]);
-    let initial_count = Word::from([Felt::new(0), Felt::new(0), Felt::new(0), Felt::new(0)]);
-    let counter_cfg = AccountCreationConfig {
-        storage_slots: vec![miden_client::account::StorageSlot::Map(
-            StorageMap::with_entries([(count_storage_key, initial_count)])?,
-        )],
-        ..Default::default()
-    };
-
-    // create testing counter account
-    let counter_account =
-        create_testing_account_from_package(contract_package.clone(), counter_cfg).await?;
+    let setup = counter_setup().await?;
+    let counter_account = setup.counter_account.clone();
 
     // create testing increment note
     let counter_note = create_testing_note_from_package(
-        note_package.clone(),
-        sender.id(),
+        setup.note_package.clone(),
+        setup.sender.id(),
         NoteCreationConfig::default(),
     )?;
 
-    // add counter account and note to mockchain
-    builder.add_account(counter_account.clone())?;
-    builder.add_note(OutputNote::Full(counter_note.clone().into()));
-
-    // Build the mock chain
-    let mut mock_chain = builder.build()?;
-
-    // Get transaction inputs (for increment the count)
-    let tx_inputs = mock_chain.get_transaction_inputs(
-        counter_account.clone(),
-        None,
-        &[counter_note.id()],
-        &[],
-    )?;
-
-    // Build the transaction context
-    let tx_context = TransactionContextBuilder::new(counter_account.clone())
-        .account_seed(None)
-        .tx_inputs(tx_inputs)
-        .build()?;
-
-    // Execute the transaction
-    let executed_transaction = tx_context.execute().await?;
-
-    // Add the executed transaction to the mockchain
-    let updated_counter_account =
-        mock_chain.add_pending_executed_transaction(&executed_transaction)?;
+    // Execute the transaction consuming the note on the counter account
+    let updated_counter_account = execute_on_counter(setup, counter_note).await?;
 
     // Get the count from the updated counter account
-    let count = updated_counter_account
-        .storage()
-        .get_map_item(0, count_storage_key)?;
+    let count = read_typed(&updated_counter_account, &StorageSchema::counter())?["count"];
 
     // Assert that the count value is equal to 1 after executing the transaction
     assert_eq!(
         count,
-        Word::from([Felt::new(0), Felt::new(0), Felt::new(0), Felt::new(1)]),
-        "Count value is not equal to 1"
+        word_from_u64(1),
+        "Count value is not equal to 1, account changes: {:#?}",
+        account_state_diff(&counter_account, &updated_counter_account)
     );
 
     println!("Test passed!");
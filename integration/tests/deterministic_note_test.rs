@@ -0,0 +1,38 @@
+use integration::helpers::{
+    build_project_in_dir_async, create_note_deterministic, word_from_u64, NoteCreationConfig,
+};
+
+use miden_client::account::AccountId;
+use miden_objects::testing::account_id::ACCOUNT_ID_PRIVATE_SENDER;
+use std::{path::Path, sync::Arc};
+
+#[tokio::test]
+async fn deterministic_note_test() -> anyhow::Result<()> {
+    // Test that the note ID only depends on the inputs, including the serial number
+    let note_package =
+        Arc::new(build_project_in_dir_async(Path::new("../contracts/increment-note"), true).await?);
+    let sender_id = AccountId::try_from(ACCOUNT_ID_PRIVATE_SENDER)?;
+
+    let note = create_note_deterministic(
+        word_from_u64(1),
+        note_package.clone(),
+        sender_id,
+        NoteCreationConfig::default(),
+    )?;
+    let same_note = create_note_deterministic(
+        word_from_u64(1),
+        note_package.clone(),
+        sender_id,
+        NoteCreationConfig::default(),
+    )?;
+    let other_note = create_note_deterministic(
+        word_from_u64(2),
+        note_package,
+        sender_id,
+        NoteCreationConfig::default(),
+    )?;
+
+    assert_eq!(note.id(), same_note.id());
+    assert_ne!(note.id(), other_note.id());
+    Ok(())
+}
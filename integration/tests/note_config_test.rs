@@ -0,0 +1,37 @@
+use integration::helpers::NoteCreationConfig;
+
+use miden_client::note::{NoteExecutionHint, NoteTag, NoteType};
+
+#[test]
+fn note_config_presets_test() -> anyhow::Result<()> {
+    // Test that the presets set the requested execution hint and keep the other defaults
+    let after_block = NoteCreationConfig::after_block(100)?;
+    assert_eq!(
+        after_block.execution_hint,
+        NoteExecutionHint::after_block(100.into())?
+    );
+    assert_eq!(after_block.note_type, NoteType::Public);
+    after_block.validate()?;
+
+    let on_block_slot = NoteCreationConfig::on_block_slot(10, 4, 2);
+    assert_eq!(
+        on_block_slot.execution_hint,
+        NoteExecutionHint::on_block_slot(10, 4, 2)
+    );
+    on_block_slot.validate()?;
+
+    Ok(())
+}
+
+#[test]
+fn note_config_rejects_private_network_note_test() -> anyhow::Result<()> {
+    // Test that a network execution tag can't be combined with a private note
+    let config = NoteCreationConfig {
+        note_type: NoteType::Private,
+        tag: NoteTag::for_public_use_case(0, 0, miden_client::note::NoteExecutionMode::Network)?,
+        ..Default::default()
+    };
+
+    assert!(config.validate().is_err());
+    Ok(())
+}
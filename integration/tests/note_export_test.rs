@@ -0,0 +1,63 @@
+mod common;
+
+use common::{consume_on_counter, counter_setup, mock_client};
+use integration::helpers::{
+    create_basic_wallet_account, create_note_from_package, export_note, import_note, submit_intent,
+    word_from_u64, AccountCreationConfig, Intent, NoteCreationConfig,
+};
+
+use miden_client::{note::Note, testing::mock::MockRpcApi};
+use std::sync::Arc;
+
+#[tokio::test]
+async fn note_export_test() -> anyhow::Result<()> {
+    // Test that an exported increment note can be imported and consumed by the recipient
+    let setup = counter_setup().await?;
+    let rpc = Arc::new(MockRpcApi::default());
+
+    // Publish and export the increment note on the sender side
+    let mut sender = mock_client(rpc.clone()).await?;
+    let sender_account = create_basic_wallet_account(
+        &mut sender.client,
+        sender.keystore.clone(),
+        AccountCreationConfig::default(),
+    )
+    .await?;
+    let counter_note = create_note_from_package(
+        &mut sender.client,
+        setup.note_package.clone(),
+        sender_account.id(),
+        NoteCreationConfig::default(),
+    )?;
+    let exported = export_note(&sender.client, &counter_note).await?;
+    submit_intent(
+        &mut sender.client,
+        sender_account.id(),
+        Intent::PublishNote(counter_note.clone()),
+    )
+    .await?;
+    rpc.prove_block();
+
+    // Corrupt bytes are rejected with a descriptive error
+    let mut recipient = mock_client(rpc).await?;
+    let err = import_note(&mut recipient.client, &exported[..exported.len() / 2])
+        .await
+        .expect_err("truncated note file was imported");
+    assert!(format!("{err:#}").contains("corrupt"), "{err:#}");
+
+    // Import the note on the recipient side
+    let note_id = import_note(&mut recipient.client, &exported).await?;
+    assert_eq!(note_id, counter_note.id());
+
+    let record = recipient
+        .client
+        .get_input_note(note_id)
+        .await?
+        .expect("imported note is stored by the recipient");
+
+    // Consume the imported note
+    let count = consume_on_counter(setup, Note::try_from(record)?).await?;
+    assert_eq!(count, word_from_u64(1), "Count value is not equal to 1");
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use integration::helpers::{
+    account_id_from_note_inputs, account_id_to_note_inputs, validate_note_inputs, NoteError,
+    NoteInputsBuilder,
+};
+
+use miden_client::{account::AccountId, Felt};
+use miden_objects::{
+    constants::MAX_INPUTS_PER_NOTE,
+    testing::account_id::{
+        ACCOUNT_ID_PRIVATE_SENDER, ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET,
+        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
+    },
+};
+
+#[test]
+fn account_id_note_inputs_round_trip_test() -> anyhow::Result<()> {
+    // Test that account IDs of several types survive encoding into note inputs
+    for id in [
+        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
+        ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET,
+        ACCOUNT_ID_PRIVATE_SENDER,
+    ] {
+        let account_id = AccountId::try_from(id)?;
+
+        let inputs = account_id_to_note_inputs(account_id);
+        assert_eq!(inputs, [account_id.suffix(), account_id.prefix().as_felt()]);
+        assert_eq!(account_id_from_note_inputs(&inputs)?, account_id);
+
+        // Trailing inputs are ignored, matching the builder's layout
+        let inputs = NoteInputsBuilder::default()
+            .push_account_id(account_id)
+            .push_felt(Felt::new(7))
+            .build();
+        assert_eq!(account_id_from_note_inputs(&inputs)?, account_id);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn account_id_from_short_note_inputs_test() {
+    // Test that decoding fails when the inputs are too short to hold an account ID
+    assert!(account_id_from_note_inputs(&[Felt::new(1)]).is_err());
+}
+
+#[test]
+fn validate_note_inputs_test() {
+    // Test that inputs are accepted up to the protocol limit and rejected with a typed error
+    // beyond it
+    let inputs = vec![Felt::new(1); MAX_INPUTS_PER_NOTE + 1];
+
+    assert!(validate_note_inputs(&inputs[..MAX_INPUTS_PER_NOTE]).is_ok());
+
+    let err = validate_note_inputs(&inputs).unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<NoteError>(),
+        Some(NoteError::TooManyInputs { count, max })
+            if *count == MAX_INPUTS_PER_NOTE + 1 && *max == MAX_INPUTS_PER_NOTE
+    ));
+}
@@ -0,0 +1,64 @@
+mod common;
+
+use common::{consume_on_counter, counter_setup, mock_client};
+use integration::helpers::{
+    create_basic_wallet_account, create_note_from_package, import_note_file, publish_private_note,
+    word_from_u64, AccountCreationConfig, NoteCreationConfig,
+};
+
+use miden_client::{
+    note::{Note, NoteType},
+    testing::mock::MockRpcApi,
+};
+use std::sync::Arc;
+
+#[tokio::test]
+async fn private_note_test() -> anyhow::Result<()> {
+    // Test that a private increment note can be shared out of band and consumed
+    let setup = counter_setup().await?;
+    let rpc = Arc::new(MockRpcApi::default());
+
+    // Publish a private increment note from the sender's client
+    let mut sender = mock_client(rpc.clone()).await?;
+    let sender_account = create_basic_wallet_account(
+        &mut sender.client,
+        sender.keystore.clone(),
+        AccountCreationConfig::default(),
+    )
+    .await?;
+    let counter_note = create_note_from_package(
+        &mut sender.client,
+        setup.note_package.clone(),
+        sender_account.id(),
+        NoteCreationConfig {
+            note_type: NoteType::Private,
+            ..Default::default()
+        },
+    )?;
+    let (_, note_file) = publish_private_note(
+        &mut sender.client,
+        sender_account.id(),
+        counter_note.clone(),
+    )
+    .await?;
+    rpc.prove_block();
+
+    // Register the note file with the recipient's client
+    let mut recipient = mock_client(rpc).await?;
+    let note_id = import_note_file(&mut recipient.client, &note_file).await?;
+    assert_eq!(note_id, counter_note.id());
+
+    let record = recipient
+        .client
+        .get_input_note(note_id)
+        .await?
+        .expect("imported note is stored by the recipient");
+    let imported_note = Note::try_from(record)?;
+    assert_eq!(imported_note.metadata().note_type(), NoteType::Private);
+
+    // Consume the imported note as an unauthenticated input note
+    let count = consume_on_counter(setup, imported_note).await?;
+    assert_eq!(count, word_from_u64(1), "Count value is not equal to 1");
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use integration::helpers::{
+    build_project_in_dir_async, create_testing_note_from_package, export_request, import_request,
+    NoteCreationConfig,
+};
+
+use miden_client::transaction::TransactionRequestBuilder;
+use miden_testing::{Auth, MockChain};
+use std::{path::Path, sync::Arc};
+
+#[tokio::test]
+async fn request_export_test() -> anyhow::Result<()> {
+    // Test that the counter's consume request survives an export and import round trip
+    let mut builder = MockChain::builder();
+    let sender = builder.add_existing_wallet(Auth::BasicAuth)?;
+
+    let note_package =
+        Arc::new(build_project_in_dir_async(Path::new("../contracts/increment-note"), true).await?);
+    let counter_note = create_testing_note_from_package(
+        note_package.clone(),
+        sender.id(),
+        NoteCreationConfig::default(),
+    )?;
+
+    let consume_request = TransactionRequestBuilder::new()
+        .unauthenticated_input_notes([(counter_note.clone(), None)])
+        .build()?;
+
+    let bytes = export_request(&consume_request);
+    let imported = import_request(&bytes)?;
+
+    let imported_note_ids: Vec<_> = imported
+        .unauthenticated_input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect();
+    assert_eq!(imported_note_ids, vec![counter_note.id()]);
+    assert_eq!(export_request(&imported), bytes);
+
+    // Truncated bytes are rejected instead of producing a partial request
+    assert!(import_request(&bytes[..bytes.len() / 2]).is_err());
+
+    Ok(())
+}